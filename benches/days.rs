@@ -0,0 +1,95 @@
+//! Times parsing and each part of every day, over several runs.
+//!
+//! `cargo bench` runs all days, `cargo bench -- 16 19` only the given ones.
+//! Each day is run until about a second has passed, with at least 3 and at
+//! most 100 samples, and the fastest and median time of each phase is reported.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use adventofcode_2022::{input::Source, solver, Part, Run, DAYS};
+
+const TARGET: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 3;
+const MAX_SAMPLES: usize = 100;
+
+// fastest and median of each phase
+struct Stats {
+    min: Duration,
+    median: Duration,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>) -> Self {
+        times.sort();
+        Self {
+            min: times[0],
+            median: times[times.len() / 2],
+        }
+    }
+}
+
+fn main() {
+    // cargo passes --bench to custom harnesses
+    let days: Vec<usize> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|arg| arg.parse().expect("expected a day number"))
+        .collect();
+    let days = if days.is_empty() {
+        (1..=DAYS).collect()
+    } else {
+        days
+    };
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>7}",
+        "day", "phase", "min", "median", "samples"
+    );
+    for day in days {
+        let solve = solver(day).expect("no solution for day");
+        let input = match Source::Day(day).read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("skipping day {}: {}", day, e);
+                continue;
+            }
+        };
+
+        let mut runs: Vec<Run> = Vec::new();
+        let start = Instant::now();
+        while runs.len() < MIN_SAMPLES || (runs.len() < MAX_SAMPLES && start.elapsed() < TARGET) {
+            runs.push(solve(&input, &Part::BOTH).expect("cannot parse input"));
+        }
+
+        let phases = [
+            ("parse", runs.iter().map(|run| run.parse_time).collect()),
+            (
+                "part 1",
+                runs.iter()
+                    .filter_map(|run| run.part_time(Part::One))
+                    .collect(),
+            ),
+            (
+                "part 2",
+                runs.iter()
+                    .filter_map(|run| run.part_time(Part::Two))
+                    .collect(),
+            ),
+            ("total", runs.iter().map(|run| run.total_time()).collect()),
+        ];
+        for (phase, times) in phases {
+            let stats = Stats::new(times);
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>7}",
+                day,
+                phase,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                runs.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode_2022::day19::{parse_blueprint, solve_blueprint};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    // a short time keeps the search quick
+    if let Ok(blueprint) = parse_blueprint(text) {
+        solve_blueprint(8, &blueprint.costs);
+    }
+});
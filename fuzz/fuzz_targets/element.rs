@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode_2022::day13::Element;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    // whatever parses prints back the same packet
+    if let Ok(packet) = text.parse::<Element>() {
+        assert_eq!(packet.to_string().parse::<Element>(), Ok(packet));
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use adventofcode_2022::{day21::Day21, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = Day21::parse(text);
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use adventofcode_2022::day25::{Snafu, SnafuDigit};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let digits = data.iter().map(|&b| SnafuDigit::try_from(b));
+    let _ = digits.collect::<Result<Snafu, _>>();
+    if let Ok(text) = std::str::from_utf8(data) {
+        if let Ok(snafu) = text.parse::<Snafu>() {
+            let _ = isize::try_from(&snafu);
+            let _ = snafu.clone() + snafu;
+        }
+    }
+});
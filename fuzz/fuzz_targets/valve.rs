@@ -0,0 +1,8 @@
+#![no_main]
+
+use adventofcode_2022::day16::Valve;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = text.parse::<Valve>();
+});
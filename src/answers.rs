@@ -0,0 +1,145 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    input::{input_dir, InputError, Source},
+    Part,
+};
+
+/// File in the [input directory](input_dir) with the expected answers.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Expected answers to the puzzles, one `<day> <part> <answer>` per line.
+///
+/// Blank lines and lines starting with `#` are ignored. Multi-line answers are
+/// written on a single line with `\n` escapes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    expected: HashMap<(usize, Part), String>,
+}
+
+impl Answers {
+    /// Reads the answers file from the input directory.
+    pub fn load() -> Result<Self, AnswersError> {
+        let source = Source::Path(input_dir().join(ANSWERS_FILE));
+        let text = source.read().map_err(AnswersError::Read)?;
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let mut expected = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |reason: &str| AnswersError::Parse {
+                line: i + 1,
+                reason: reason.to_string(),
+            };
+
+            let mut toks = line.splitn(3, ' ');
+            let day: usize = toks
+                .next()
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| error("invalid day"))?;
+            let part: Part = toks
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(|| error("invalid part"))?;
+            let answer = toks.next().ok_or_else(|| error("missing answer"))?;
+            if expected.insert((day, part), unescape(answer)).is_some() {
+                return Err(error("duplicate answer"));
+            }
+        }
+        Ok(Self { expected })
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// Writes an answer on a single line, as in the answers file.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Reads an answer written with [`escape`].
+pub fn unescape(answer: &str) -> String {
+    let mut res = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                res.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                res.push('\\');
+                chars.next();
+            }
+            (c, _) => res.push(c),
+        }
+    }
+    res
+}
+
+/// The answers file could not be loaded.
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(InputError),
+    Parse { line: usize, reason: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(e) => write!(f, "{}", e),
+            Self::Parse { line, reason } => write!(f, "{} line {}: {}", ANSWERS_FILE, line, reason),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read(e) => Some(e),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse("# comment\n\n1 1 42\n10 2 #..#\\n.##.\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("42"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(10, Part::Two), Some("#..#\n.##."));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Answers::parse("1 1 42\n1 3 7"),
+            Err(AnswersError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("1 1 42\n1 1 43"),
+            Err(AnswersError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("1 1"),
+            Err(AnswersError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn escaping() {
+        let answer = "a\\b\nc";
+        assert_eq!(unescape(&escape(answer)), answer);
+    }
+}
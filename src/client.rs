@@ -0,0 +1,678 @@
+//! Talking to the Advent of Code website, to download the puzzle inputs and
+//! submit the answers.
+//!
+//! The requests are authenticated with the session cookie of a logged in
+//! user, taken from `$AOC_SESSION` or from a `.session` file in the
+//! [input directory](input_dir). `$AOC_BASE_URL` sends them somewhere else
+//! than the website, e.g. to a local server.
+//!
+//! ```no_run
+//! use adventofcode_2022::client::{Client, Config};
+//!
+//! let client = Client::new(Config::from_env()?);
+//! println!("{}", client.fetch(1)?.path().display());
+//! # Ok::<(), adventofcode_2022::client::ClientError>(())
+//! ```
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answers::{escape, unescape},
+    debug, info,
+    input::input_dir,
+    Part,
+};
+
+pub const YEAR: usize = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Not to hammer the website, at least this much passes between two requests.
+pub const DEFAULT_MIN_DELAY: Duration = Duration::from_secs(5);
+
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the minimum delay, in seconds.
+pub const MIN_DELAY_VAR: &str = "AOC_MIN_DELAY";
+
+/// File in the input directory with the session token.
+pub const SESSION_FILE: &str = ".session";
+/// File in the input directory recording the answers that were checked.
+pub const SUBMISSIONS_FILE: &str = "submissions.txt";
+// file in the input directory with the earliest time of the next request,
+// which has to outlive the process
+const NEXT_REQUEST_FILE: &str = ".next-request";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    /// Where the inputs are cached, and the state of the client kept.
+    pub input_dir: PathBuf,
+    pub min_delay: Duration,
+}
+
+impl Config {
+    /// Configuration from the environment, with defaults for the website.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let input_dir = input_dir();
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) => Some(session),
+            Err(_) => fs::read_to_string(input_dir.join(SESSION_FILE)).ok(),
+        };
+        let min_delay = match env::var(MIN_DELAY_VAR) {
+            Ok(secs) => secs
+                .parse()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(|| {
+                    ClientError::Config(format!("{}: invalid delay '{}'", MIN_DELAY_VAR, secs))
+                })?,
+            Err(_) => DEFAULT_MIN_DELAY,
+        };
+        Ok(Self {
+            base_url: env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: session
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            input_dir,
+            min_delay,
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{}", self.base_url.trim_end_matches('/'), YEAR, path)
+    }
+}
+
+/// Where a downloaded input was put.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// It was there already, nothing was requested.
+    Cached(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Downloaded(path) | Self::Cached(path) => path,
+        }
+    }
+}
+
+/// What the website said about an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// With whether it is too high or too low, when the website tells.
+    Wrong(Option<Hint>),
+    /// The previous answer was too recent, this one was not checked.
+    Wait(Duration),
+    /// The part is already solved, or still locked.
+    WrongLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict in the page answering a submission.
+    pub fn parse(page: &str) -> Option<Self> {
+        let text = page_text(page);
+        if text.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Self::Wrong(hint))
+        } else if text.contains("You gave an answer too recently") {
+            // "You have 1m 23s left to wait."
+            let left = text
+                .split("You have ")
+                .nth(1)?
+                .split(" left to wait")
+                .next()?;
+            let mut wait = Duration::ZERO;
+            for tok in left.split_whitespace() {
+                let (n, unit) = tok.split_at(tok.find(|c: char| !c.is_ascii_digit())?);
+                let n: u64 = n.parse().ok()?;
+                wait += match unit {
+                    "h" => Duration::from_secs(n * 3600),
+                    "m" => Duration::from_secs(n * 60),
+                    "s" => Duration::from_secs(n),
+                    _ => return None,
+                };
+            }
+            Some(Self::Wait(wait))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    // as written in the submissions file, only for checked answers
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::Correct => Some("correct"),
+            Self::Wrong(None) => Some("wrong"),
+            Self::Wrong(Some(Hint::TooHigh)) => Some("too-high"),
+            Self::Wrong(Some(Hint::TooLow)) => Some("too-low"),
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Self::Correct),
+            "wrong" => Some(Self::Wrong(None)),
+            "too-high" => Some(Self::Wrong(Some(Hint::TooHigh))),
+            "too-low" => Some(Self::Wrong(Some(Hint::TooLow))),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong(None) => write!(f, "wrong"),
+            Self::Wrong(Some(Hint::TooHigh)) => write!(f, "wrong, too high"),
+            Self::Wrong(Some(Hint::TooLow)) => write!(f, "wrong, too low"),
+            Self::Wait(wait) => write!(f, "not checked, wait {}s", wait.as_secs()),
+            Self::WrongLevel => write!(f, "not checked, already solved or locked"),
+        }
+    }
+}
+
+// the text of the main part of a page, without the markup
+fn page_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    for (i, chunk) in article.split('<').enumerate() {
+        // the first chunk starts out of a tag
+        let outside = if i == 0 {
+            Some(chunk)
+        } else {
+            chunk.split_once('>').map(|(_, t)| t)
+        };
+        text.push_str(outside.unwrap_or_default());
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// how long the website wants to hear nothing after a wrong answer, e.g.
+// "Please wait one minute before trying again."
+fn penalty(page: &str) -> Option<Duration> {
+    const NUMBERS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    let text = page_text(page).to_lowercase();
+    let mut toks = text.split("please wait ").nth(1)?.split(' ');
+    let n = toks.next()?;
+    let n = match NUMBERS.iter().position(|&word| word == n) {
+        Some(i) => i as u64 + 1,
+        None => n.parse().ok()?,
+    };
+    match toks.next()? {
+        "minute" | "minutes" => Some(Duration::from_secs(n * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+/// An answer that was checked by the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: usize,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    fn parse(line: &str) -> Option<Self> {
+        let mut toks = line.splitn(4, ' ');
+        Some(Self {
+            day: toks.next()?.parse().ok()?,
+            part: toks.next()?.parse().ok()?,
+            verdict: Verdict::from_name(toks.next()?)?,
+            answer: unescape(toks.next()?),
+        })
+    }
+}
+
+/// Outcome of submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submitted {
+    Sent(Verdict),
+    /// The answer, or a correct one, was checked before, nothing was sent.
+    Recorded(Submission),
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+        Self { config, agent }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Downloads the input of `day` to `dayN.txt` in the input directory,
+    /// unless it is already there.
+    pub fn fetch(&self, day: usize) -> Result<Fetched, ClientError> {
+        let path = self.config.input_dir.join(format!("day{}.txt", day));
+        // an empty file is only a placeholder
+        if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = self.config.url(&format!("/day/{}/input", day));
+        let request = self.agent.get(&url).header("Cookie", &self.cookie()?);
+        self.wait()?;
+        debug!("GET {}", url);
+        let mut response = request
+            .call()
+            .map_err(|e| ClientError::Request(url.clone(), e))?;
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| ClientError::Request(url.clone(), e))?;
+        if status != 200 {
+            return Err(ClientError::Status { url, status, body });
+        }
+
+        // never leave a partial input behind
+        let partial = path.with_extension("part");
+        fs::write(&partial, body)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| ClientError::Io(path.clone(), e))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Posts an answer, unless the same one or a correct one was submitted
+    /// before, and records the verdict in the submissions file.
+    pub fn submit(&self, day: usize, part: Part, answer: &str) -> Result<Submitted, ClientError> {
+        let submissions = self.submissions()?;
+        let previous = submissions
+            .into_iter()
+            .filter(|s| s.day == day && s.part == part)
+            .find(|s| s.verdict == Verdict::Correct || s.answer == answer);
+        if let Some(submission) = previous {
+            return Ok(Submitted::Recorded(submission));
+        }
+
+        let url = self.config.url(&format!("/day/{}/answer", day));
+        let level = (part as usize).to_string();
+        let request = self.agent.post(&url).header("Cookie", &self.cookie()?);
+        self.wait()?;
+        debug!("POST {}", url);
+        let mut response = request
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|e| ClientError::Request(url.clone(), e))?;
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| ClientError::Request(url.clone(), e))?;
+        if status != 200 {
+            return Err(ClientError::Status { url, status, body });
+        }
+        let verdict = Verdict::parse(&body).ok_or(ClientError::Response(url, page_text(&body)))?;
+
+        match verdict {
+            Verdict::Wait(wait) => self.hold(wait)?,
+            Verdict::Wrong(_) => {
+                if let Some(wait) = penalty(&body) {
+                    self.hold(wait)?;
+                }
+            }
+            _ => (),
+        }
+        if let Some(name) = verdict.name() {
+            let path = self.config.input_dir.join(SUBMISSIONS_FILE);
+            let line = format!("{} {} {} {}\n", day, part, name, escape(answer));
+            fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| io::Write::write_all(&mut file, line.as_bytes()))
+                .map_err(|e| ClientError::Io(path, e))?;
+        }
+        Ok(Submitted::Sent(verdict))
+    }
+
+    /// The answers checked so far, one `<day> <part> <verdict> <answer>` per
+    /// line of the submissions file.
+    pub fn submissions(&self) -> Result<Vec<Submission>, ClientError> {
+        let path = self.config.input_dir.join(SUBMISSIONS_FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(ClientError::Io(path, e)),
+        };
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                Submission::parse(line).ok_or_else(|| {
+                    ClientError::Config(format!(
+                        "{} line {}: invalid submission",
+                        path.display(),
+                        i + 1
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        match &self.config.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(ClientError::MissingSession),
+        }
+    }
+
+    // sleeps until the next request is allowed, then holds off the following
+    // one for the minimum delay
+    fn wait(&self) -> Result<(), ClientError> {
+        let path = self.config.input_dir.join(NEXT_REQUEST_FILE);
+        let next = fs::read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(delay) = next.and_then(|next| next.duration_since(SystemTime::now()).ok()) {
+            info!("waiting {:.2?} before the next request", delay);
+            thread::sleep(delay);
+        }
+        self.hold(self.config.min_delay)
+    }
+
+    // no requests for the given time from now
+    fn hold(&self, delay: Duration) -> Result<(), ClientError> {
+        let path = self.config.input_dir.join(NEXT_REQUEST_FILE);
+        let next = SystemTime::now() + delay;
+        let millis = next
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        fs::write(&path, millis.to_string()).map_err(|e| ClientError::Io(path, e))
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Config(String),
+    MissingSession,
+    Request(String, ureq::Error),
+    /// The server answered with an error.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The server answered something that could not be understood.
+    Response(String, String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config(reason) => write!(f, "{}", reason),
+            Self::MissingSession => write!(
+                f,
+                "missing session token, set ${} or write it to {}",
+                SESSION_VAR,
+                input_dir().join(SESSION_FILE).display()
+            ),
+            Self::Request(url, e) => write!(f, "{}: {}", url, e),
+            Self::Status { url, status, body } => {
+                write!(f, "{}: status {}", url, status)?;
+                match body.lines().next() {
+                    Some(line) if !line.trim().is_empty() => write!(f, ": {}", line.trim()),
+                    _ => Ok(()),
+                }
+            }
+            Self::Response(url, text) => write!(f, "{}: unexpected response: {}", url, text),
+            Self::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request(_, e) => Some(e),
+            Self::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        process,
+        thread::JoinHandle,
+        time::Instant,
+    };
+
+    use super::*;
+
+    // answers the requests in turn with the given status and body, returns
+    // what was requested
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = len.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn config(name: &str, base_url: String) -> Config {
+        let input_dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        fs::create_dir_all(&input_dir).unwrap();
+        Config {
+            base_url,
+            session: Some("53cr37".to_string()),
+            input_dir,
+            min_delay: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn fetch() {
+        let (url, server) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = Client::new(config("fetch", url));
+        let path = client.config().input_dir.join("day1.txt");
+
+        assert_eq!(client.fetch(1).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        // the second time it is not requested
+        assert_eq!(client.fetch(1).unwrap(), Fetched::Cached(path));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("cookie: session=53cr37\r\n"));
+    }
+
+    #[test]
+    fn fetch_errors() {
+        let (url, server) = serve(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        )]);
+        let client = Client::new(config("fetch-errors", url));
+        let err = client.fetch(25).unwrap_err();
+        assert!(matches!(err, ClientError::Status { status: 404, .. }));
+        assert!(err.to_string().ends_with(
+            ": status 404: Please don't repeatedly request this endpoint before it unlocks!"
+        ));
+        assert!(!client.config().input_dir.join("day25.txt").exists());
+        server.join().unwrap();
+
+        let mut config = config("no-session", String::new());
+        config.session = None;
+        assert!(matches!(
+            Client::new(config).fetch(3),
+            Err(ClientError::MissingSession)
+        ));
+    }
+
+    const RIGHT: &str = "<html><body><main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer to \
+        collecting enough star fruit. <a href=\"/2022/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main></body></html>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure \
+        you're using the full input data. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(Verdict::parse(RIGHT), Some(Verdict::Correct));
+        assert_eq!(
+            Verdict::parse(TOO_HIGH),
+            Some(Verdict::Wrong(Some(Hint::TooHigh)))
+        );
+        assert_eq!(penalty(TOO_HIGH), None);
+        let page = TOO_HIGH.replace("data.", "data. Please wait one minute before trying again.");
+        assert_eq!(penalty(&page), Some(Duration::from_secs(60)));
+        let page = "<article><p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p></article>";
+        assert_eq!(
+            Verdict::parse(page),
+            Some(Verdict::Wrong(Some(Hint::TooLow)))
+        );
+        assert_eq!(penalty(page), Some(Duration::from_secs(300)));
+        let page = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before \
+            trying again.  You have 1m 23s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+        assert_eq!(
+            Verdict::parse(page),
+            Some(Verdict::Wait(Duration::from_secs(83)))
+        );
+        let page = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+        assert_eq!(Verdict::parse(page), Some(Verdict::WrongLevel));
+        assert_eq!(Verdict::parse("<html>Not Found</html>"), None);
+    }
+
+    #[test]
+    fn submit() {
+        let (url, server) = serve(vec![(200, TOO_HIGH), (200, RIGHT)]);
+        let client = Client::new(config("submit", url));
+
+        let too_high = Verdict::Wrong(Some(Hint::TooHigh));
+        assert_eq!(
+            client.submit(1, Part::One, "75000").unwrap(),
+            Submitted::Sent(too_high)
+        );
+        // the same wrong answer is not sent again
+        let first = Submission {
+            day: 1,
+            part: Part::One,
+            answer: "75000".to_string(),
+            verdict: too_high,
+        };
+        assert_eq!(
+            client.submit(1, Part::One, "75000").unwrap(),
+            Submitted::Recorded(first.clone())
+        );
+        assert_eq!(
+            client.submit(1, Part::One, "70613").unwrap(),
+            Submitted::Sent(Verdict::Correct)
+        );
+        // nor anything once it is right
+        let Ok(Submitted::Recorded(second)) = client.submit(1, Part::One, "1") else {
+            panic!("submitted after the right answer");
+        };
+        assert_eq!(second.answer, "70613");
+        assert_eq!(client.submissions().unwrap(), vec![first, second]);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=75000"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=70613"));
+    }
+
+    #[test]
+    fn min_delay() {
+        let (url, server) = serve(vec![(200, "a"), (200, "b")]);
+        let mut config = config("min-delay", url);
+        config.min_delay = Duration::from_millis(300);
+        let client = Client::new(config);
+
+        let start = Instant::now();
+        client.fetch(1).unwrap();
+        client.fetch(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        server.join().unwrap();
+    }
+}
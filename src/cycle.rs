@@ -0,0 +1,175 @@
+//! Shortcuts through simulations that end up repeating themselves.
+//!
+//! A simulation is run one step at a time, each step adding to a total (the
+//! height of a tower, a score), until a state comes back: from then on the
+//! steps repeat, and so does what they add, which gives the total after any
+//! number of steps.
+//!
+//! ```
+//! use adventofcode_2022::cycle;
+//!
+//! // a counter that goes 0, 1, 2, 3, 4, 2, 3, 4, 2, ... adding its value
+//! let history = cycle::detect(0, 100, |&n| n, |n| {
+//!     *n = if *n == 4 { 2 } else { *n + 1 };
+//!     *n
+//! });
+//! let cycle = history.cycle().unwrap();
+//! assert_eq!((cycle.start, cycle.period, cycle.delta), (2, 3, 9));
+//! assert_eq!(history.at(4), Some(1 + 2 + 3 + 4));
+//! assert_eq!(history.at(1_000_000), Some(1 + 333_333 * 9));
+//! ```
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Where the steps of a simulation start repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the first repetition.
+    pub start: usize,
+    /// Steps in each repetition.
+    pub period: usize,
+    /// What each repetition adds to the total.
+    pub delta: usize,
+}
+
+/// The totals of the steps of a simulation, up to where it repeats.
+#[derive(Debug, Clone)]
+pub struct History {
+    // the total after each number of steps, from none
+    totals: Vec<usize>,
+    cycle: Option<Cycle>,
+}
+
+impl History {
+    /// The cycle found, `None` if the simulation did not repeat within the
+    /// limit.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// The total after `steps` steps, `None` if they are past the limit and
+    /// no cycle was found.
+    pub fn at(&self, steps: usize) -> Option<usize> {
+        if let Some(&total) = self.totals.get(steps) {
+            return Some(total);
+        }
+        let Cycle {
+            start,
+            period,
+            delta,
+        } = self.cycle?;
+        let (periods, rest) = ((steps - start) / period, (steps - start) % period);
+        Some(self.totals[start + rest] + periods * delta)
+    }
+}
+
+/// Runs `step` on `state` until the `key` of a state is seen again, or for
+/// `limit` steps. Each step returns what it adds to the total.
+///
+/// The key must tell apart the states that do not go on the same way, but
+/// need not hold all of the state: the total, for one, is kept separately.
+pub fn detect<S, K: Hash + Eq>(
+    mut state: S,
+    limit: usize,
+    mut key: impl FnMut(&S) -> K,
+    mut step: impl FnMut(&mut S) -> usize,
+) -> History {
+    let mut seen = HashMap::new();
+    let mut totals = vec![0];
+    for steps in 0..limit {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(first) => {
+                let start = *first.get();
+                let cycle = Cycle {
+                    start,
+                    period: steps - start,
+                    delta: totals[steps] - totals[start],
+                };
+                return History {
+                    totals,
+                    cycle: Some(cycle),
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+        let total = totals[steps] + step(&mut state);
+        totals.push(total);
+    }
+    History {
+        totals,
+        cycle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::Rng;
+
+    // a random function on a few values, where every walk ends in a loop
+    fn random_walk(rng: &mut Rng, values: usize) -> Vec<usize> {
+        (0..values).map(|_| rng.below(values)).collect()
+    }
+
+    #[test]
+    fn extrapolation() {
+        let mut rng = Rng::new(24);
+        for _ in 0..200 {
+            let values = rng.below(30) + 1;
+            let next = random_walk(&mut rng, values);
+            let start = rng.below(next.len());
+            let limit = rng.below(40);
+            let history = detect(
+                start,
+                limit,
+                |&n| n,
+                |n| {
+                    *n = next[*n];
+                    *n
+                },
+            );
+
+            let mut n = start;
+            let mut total = 0;
+            for steps in 0..300 {
+                match history.at(steps) {
+                    Some(at) => assert_eq!(at, total, "{:?} from {} after {}", next, start, steps),
+                    None => assert!(steps > limit && history.cycle().is_none()),
+                }
+                n = next[n];
+                total += n;
+            }
+        }
+    }
+
+    #[test]
+    fn no_cycle() {
+        let history = detect(
+            0_usize,
+            10,
+            |&n| n,
+            |n| {
+                *n += 1;
+                1
+            },
+        );
+        assert_eq!(history.cycle(), None);
+        assert_eq!((history.at(10), history.at(11)), (Some(10), None));
+        // a state that never changes
+        let history = detect(0, 10, |&n| n % 3, |_| 2);
+        assert_eq!(
+            history.cycle(),
+            Some(Cycle {
+                start: 0,
+                period: 1,
+                delta: 2
+            })
+        );
+        assert_eq!(history.at(1_000), Some(2_000));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+
+    // total calories carried by each elf
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves: Vec<usize> = Vec::new();
+        let mut sum: usize = 0;
+
+        let mut lines = parse::lines(Self::DAY, input);
+
+        loop {
+            let l = lines.next();
+            if let Some(l) = l.filter(|l| !l.text.is_empty()) {
+                let calories: usize = l.parse(l.text)?;
+                sum += calories;
+            } else {
+                elves.push(sum);
+                sum = 0;
+            }
+            if l.is_none() {
+                break;
+            }
+        }
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        top_three(elves)[0].into()
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        let sum_top: usize = top_three(elves).iter().sum();
+        sum_top.into()
+    }
+}
+
+fn top_three(elves: &[usize]) -> [usize; 3] {
+    let mut top: [usize; 3] = [0, 0, 0];
+
+    for &sum in elves {
+        for i in 0..3 {
+            if sum >= top[i] {
+                // push back
+                for j in (i + 1..3).rev() {
+                    top[j] = top[j - 1];
+                }
+                top[i] = sum;
+                break;
+            }
+        }
+    }
+    top
+}
+
+// `size` elves, carrying a few items each
+impl Generator for Day1 {
+    const SIZE: usize = 250;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items: Vec<String> = (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=70000).to_string())
+                    .collect();
+                items.join("\n")
+            })
+            .collect();
+        elves.join("\n\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day1::solve(SAMPLE), Ok((24000.into(), 45000.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(1).read().expect("cannot read input");
+        assert_eq!(Day1::solve(&input), Ok((70613.into(), 205805.into())));
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    parse, trace, Answer, ParseError, Solution,
+};
+
+const SENSE_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+const SCREEN_W: usize = 40;
+const SCREEN_H: usize = 6;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instr {
+    Noop,
+    Addx(i32),
+}
+
+// returns the sum of the sensed signal strengths and the drawn screen
+fn execute(program: &[Instr]) -> (i32, [bool; SCREEN_W * SCREEN_H]) {
+    let mut x: i32 = 1;
+    let mut cycle: usize = 0;
+    let mut acc: i32 = 0;
+    let mut screen = [false; SCREEN_W * SCREEN_H];
+
+    for instr in program {
+        cycle += 1;
+        sense(cycle, x, &mut acc);
+        draw(cycle, x, &mut screen);
+        match *instr {
+            Instr::Noop => trace!("{:0>3} noop", cycle),
+            Instr::Addx(amt) => {
+                trace!("{:0>3} addx {}", cycle, amt);
+                cycle += 1;
+                sense(cycle, x, &mut acc);
+                draw(cycle, x, &mut screen);
+                trace!("{:0>3} x: {} += {}", cycle, x, amt);
+                x += amt;
+            }
+        }
+    }
+    (acc, screen)
+}
+
+fn sense(cycle: usize, x: i32, acc: &mut i32) {
+    if SENSE_CYCLES.contains(&cycle) {
+        let signal_stength: i32 = cycle as i32 * x;
+        debug!("{:0>3} sense x: {}, strength: {}", cycle, x, signal_stength);
+        *acc += signal_stength;
+    }
+}
+
+fn draw(cycle: usize, x: i32, screen: &mut [bool; SCREEN_W * SCREEN_H]) {
+    let pos = cycle - 1;
+    let j = pos % SCREEN_W;
+
+    if (j as i32 - x).abs() < 2 {
+        screen[pos] = true;
+    }
+}
+
+fn print_screen(screen: &[bool; SCREEN_W * SCREEN_H]) -> String {
+    let mut s = String::new();
+    for (pos, &pixel) in screen.iter().enumerate() {
+        let j = pos % SCREEN_W;
+        if pixel {
+            s.push('#');
+        } else {
+            s.push('.');
+        }
+        if j == SCREEN_W - 1 {
+            s.push('\n');
+        }
+    }
+    s
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Vec<Instr>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| {
+                let mut toks = line.text.split(' ');
+                let op = toks.next().ok_or_else(|| line.missing("operation"))?;
+                match op {
+                    "noop" => Ok(Instr::Noop),
+                    "addx" => {
+                        let amt: i32 =
+                            line.parse(toks.next().ok_or_else(|| line.missing("amount"))?)?;
+                        Ok(Instr::Addx(amt))
+                    }
+                    _ => Err(line.error(op, "unknown operation")),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        let (acc, _) = execute(program);
+        acc.into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        let (_, screen) = execute(program);
+        print_screen(&screen).trim_end().into()
+    }
+}
+
+// a program lasting `size` cycles, at most the ones drawn on the screen
+impl Generator for Day10 {
+    const SIZE: usize = SCREEN_W * SCREEN_H;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let cycles = size.min(SCREEN_W * SCREEN_H);
+        let mut res = String::new();
+        let (mut cycle, mut x): (usize, isize) = (0, 1);
+        while cycle < cycles {
+            if cycle + 1 == cycles || rng.chance(0.35) {
+                res.push_str("noop\n");
+                cycle += 1;
+            } else {
+                // keep the sprite on the screen
+                let amt = rng.range(-x.min(10)..=(SCREEN_W as isize - 1 - x).min(10));
+                res.push_str(&format!("addx {}\n", amt));
+                x += amt;
+                cycle += 2;
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    const SAMPLE_SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    const SCREEN: &str = "\
+###..####.####.#..#.####.####.#..#..##..
+#..#....#.#....#.#..#....#....#..#.#..#.
+#..#...#..###..##...###..###..####.#..#.
+###...#...#....#.#..#....#....#..#.####.
+#.#..#....#....#.#..#....#....#..#.#..#.
+#..#.####.####.#..#.####.#....#..#.#..#.";
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            Day10::solve(SAMPLE),
+            Ok((13140.into(), SAMPLE_SCREEN.into()))
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(10).read().expect("cannot read input");
+        assert_eq!(Day10::solve(&input), Ok((14820.into(), SCREEN.into())));
+    }
+}
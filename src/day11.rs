@@ -0,0 +1,291 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse::{self, Line},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add,
+    Mul,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    op: Option<Op>,
+    op_arg1: Option<usize>,
+    op_arg2: Option<usize>,
+    test_factor: usize,
+    true_dest: usize,
+    false_dest: usize,
+    inspected_count: usize,
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, worry_div_factor: usize) -> usize {
+    let mut monkeys: Vec<Rc<RefCell<Monkey>>> = monkeys
+        .iter()
+        .map(|m| Rc::new(RefCell::new(m.clone())))
+        .collect();
+
+    // lcm of test factors
+    // by working with this modulus, the worry levels wrap for all monkeys
+    let modulus: usize = monkeys.iter().map(|m| m.borrow().test_factor).product();
+
+    // simulate
+    for _ in 0..rounds {
+        for monkey in monkeys.iter() {
+            let mut monkey = monkey.borrow_mut();
+
+            while !monkey.items.is_empty() {
+                let worry_level = monkey.items.pop_front().unwrap();
+                // operation
+                let worry_level = {
+                    let arg1 = match monkey.op_arg1 {
+                        None => worry_level,
+                        Some(x) => x,
+                    };
+                    let arg2 = match monkey.op_arg2 {
+                        None => worry_level,
+                        Some(x) => x,
+                    };
+                    match monkey.op {
+                        None => worry_level,
+                        Some(Op::Add) => arg1 + arg2,
+                        Some(Op::Mul) => arg1 * arg2,
+                    }
+                };
+                // update
+                monkey.inspected_count += 1;
+                // division
+                let worry_level = worry_level / worry_div_factor;
+                // wrap worry level
+                let worry_level = worry_level % modulus;
+                // throw
+                if worry_level.is_multiple_of(monkey.test_factor) {
+                    monkeys[monkey.true_dest]
+                        .borrow_mut()
+                        .items
+                        .push_back(worry_level);
+                } else {
+                    monkeys[monkey.false_dest]
+                        .borrow_mut()
+                        .items
+                        .push_back(worry_level);
+                }
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|m| m.borrow().inspected_count);
+    let monkey_business = monkeys[monkeys.len() - 1].borrow().inspected_count
+        * monkeys[monkeys.len() - 2].borrow().inspected_count;
+    monkey_business
+}
+
+// next line of a monkey description, without the given prefix
+fn field<'a, I: Iterator<Item = Line<'a>>>(
+    lines: &mut I,
+    prefix: &str,
+) -> Result<(Line<'a>, &'a str), ParseError> {
+    let expected = || format!("expected '{}'", prefix.trim());
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::input(Day11::DAY, expected()))?;
+    match line.text.strip_prefix(prefix) {
+        Some(rest) => Ok((line, rest)),
+        None => Err(line.error(line.text, expected())),
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(Self::DAY, input);
+        let mut monkeys: Vec<Monkey> = vec![];
+
+        loop {
+            // skip Monkey line
+            field(&mut lines, "Monkey ")?;
+            let mut monkey = Monkey::default();
+            // items
+            let (line, items) = field(&mut lines, "  Starting items: ")?;
+            let toks = items.split(", ");
+            for tok in toks {
+                let worry: usize = line.parse(tok)?;
+                monkey.items.push_back(worry);
+            }
+            // operation
+            let (line, op) = field(&mut lines, "  Operation: new = ")?;
+            let mut toks = op.split(' ');
+            monkey.op_arg1 = match toks.next() {
+                Some("old") => None,
+                Some(tok) => {
+                    let n: usize = line.parse(tok)?;
+                    Some(n)
+                }
+                _ => return Err(line.missing("first operand")),
+            };
+            monkey.op = match toks.next() {
+                Some("+") => Some(Op::Add),
+                Some("*") => Some(Op::Mul),
+                Some(tok) => return Err(line.error(tok, "unknown op")),
+                None => return Err(line.missing("op")),
+            };
+            monkey.op_arg2 = match toks.next() {
+                Some("old") => None,
+                Some(tok) => {
+                    let n: usize = line.parse(tok)?;
+                    Some(n)
+                }
+                _ => return Err(line.missing("second operand")),
+            };
+            // test
+            let (line, n) = field(&mut lines, "  Test: divisible by ")?;
+            monkey.test_factor = line.parse(n)?;
+            if monkey.test_factor == 0 {
+                return Err(line.error(n, "cannot divide by zero"));
+            }
+            // send to monkeys
+            let (line, n) = field(&mut lines, "    If true: throw to monkey ")?;
+            monkey.true_dest = line.parse(n)?;
+            let (line, n) = field(&mut lines, "    If false: throw to monkey ")?;
+            monkey.false_dest = line.parse(n)?;
+
+            monkeys.push(monkey);
+
+            match lines.next() {
+                None => break,
+                Some(l) if l.text.is_empty() => (),
+                Some(l) => return Err(l.error(l.text, "expected an empty line")),
+            }
+        }
+
+        if let Some(m) = monkeys
+            .iter()
+            .position(|m| m.true_dest.max(m.false_dest) >= monkeys.len())
+        {
+            return Err(ParseError::input(
+                Self::DAY,
+                format!("monkey {} throws to an unknown monkey", m),
+            ));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 20, 3).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 10000, 1).into()
+    }
+}
+
+// `size` monkeys, at most nine so that the worry levels can be wrapped
+impl Generator for Day11 {
+    const SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let n = size.clamp(2, primes.len());
+
+        let monkeys: Vec<String> = (0..n)
+            .map(|i| {
+                let items: Vec<String> = (0..rng.below(6) + 1).map(|_| rng.range(50..=99).to_string()).collect();
+                let op = match rng.below(7) {
+                    0 => "old * old".to_string(),
+                    1..=3 => format!("old * {}", rng.range(2..=19)),
+                    _ => format!("old + {}", rng.range(1..=8)),
+                };
+                // never throw to itself
+                let mut others: Vec<usize> = (0..n).filter(|&j| j != i).collect();
+                rng.shuffle(&mut others);
+                let false_dest = others.get(1).unwrap_or(&others[0]);
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    i,
+                    items.join(", "),
+                    op,
+                    primes[i],
+                    others[0],
+                    false_dest
+                )
+            })
+            .collect();
+        monkeys.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            Day11::solve(SAMPLE),
+            Ok((10605.into(), 2713310158_usize.into()))
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let input = SAMPLE.replace("new = old * 19", "new = old ^ 19");
+        let err = Day11::parse(&input).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (11, 3, 24));
+        assert_eq!(err.text, "^");
+
+        let input: Vec<&str> = SAMPLE.lines().take(2).collect();
+        let err = Day11::parse(&input.join("\n")).unwrap_err();
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.reason, "expected 'Operation: new ='");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(11).read().expect("cannot read input");
+        assert_eq!(
+            Day11::solve(&input),
+            Ok((110888.into(), 25590400731_usize.into()))
+        );
+    }
+}
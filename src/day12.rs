@@ -0,0 +1,168 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    gen::{Generator, Rng},
+    grid::{Grid, Pos},
+    search::{Search, SearchProblem, Strategy},
+    Answer, ParseError, Solution,
+};
+
+pub struct Heightmap {
+    heights: Grid<usize>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+// walking down from the end, where the steps are checked backwards
+struct Descent<'a, G> {
+    map: &'a Heightmap,
+    is_goal: G,
+}
+
+impl<G: Fn(Pos) -> bool> SearchProblem for Descent<'_, G> {
+    type Node = Pos;
+    type Key = Pos;
+
+    fn start(&self) -> Pos {
+        self.map.end_pos
+    }
+
+    fn key(&self, &pos: &Pos) -> Pos {
+        pos
+    }
+
+    fn expand(&self, &pos: &Pos) -> Vec<(Pos, usize)> {
+        let heights = &self.map.heights;
+        heights
+            .neighbours(pos)
+            .filter(|&prev| heights[pos] <= heights[prev] + 1)
+            .map(|prev| (prev, 1))
+            .collect()
+    }
+
+    fn is_goal(&self, &pos: &Pos) -> bool {
+        (self.is_goal)(pos)
+    }
+}
+
+// the fewest steps to the end from a position that is a goal
+fn shortest_path(map: &Heightmap, is_goal: impl Fn(Pos) -> bool) -> usize {
+    let outcome = Search::new(&Descent { map, is_goal }).run(Strategy::Bfs);
+    outcome.found.expect("cannot reach the end").score
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let chars = Grid::parse(Self::DAY, input, |c| match c {
+            'S' | 'E' | 'a'..='z' => Some(c),
+            _ => None,
+        })?;
+
+        let find = |target: char, what: &str| {
+            chars
+                .positions()
+                .find(|&pos| chars[pos] == target)
+                .ok_or_else(|| ParseError::input(Self::DAY, format!("missing {} position", what)))
+        };
+        let start_pos = find('S', "start")?;
+        let end_pos = find('E', "end")?;
+
+        let heights = chars.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as usize - 'a' as usize,
+        });
+
+        Ok(Heightmap {
+            heights,
+            start_pos,
+            end_pos,
+        })
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        shortest_path(map, |pos| pos == map.start_pos).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        shortest_path(map, |pos| map.heights[pos] == 0).into()
+    }
+}
+
+// a map `size` rows high and four times as wide, where the ground rises by at
+// most one between neighbours so that every square can reach the top
+impl Generator for Day12 {
+    const SIZE: usize = 41;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let height = size.max(8);
+        let width = 4 * height;
+        let end = (rng.below(height), width / 6 + rng.below(width / 6));
+
+        // the distance from the top, walking on ground of random roughness
+        let distances = |rng: &mut Rng, max_cost: usize| {
+            let costs = Grid::from_fn(width, height, |_| rng.below(max_cost) + 1);
+            let mut dist = Grid::new(width, height, usize::MAX);
+            let mut queue = BinaryHeap::from([Reverse((0, end))]);
+            while let Some(Reverse((d, pos))) = queue.pop() {
+                if d >= dist[pos] {
+                    continue;
+                }
+                dist[pos] = d;
+                for next in dist.neighbours(pos) {
+                    queue.push(Reverse((d + costs[next], next)));
+                }
+            }
+            dist
+        };
+        // with costs up to 3, the heights of neighbours differ by at most 3 / 3
+        let (mut dist, mut step) = (distances(rng, 3), 3);
+        if dist.iter().max() < Some(&(25 * step)) {
+            (dist, step) = (distances(rng, 1), 1);
+        }
+        let start = dist.positions().max_by_key(|&pos| dist[pos]).unwrap();
+
+        let mut res = String::new();
+        for (pos, &d) in dist.positions().zip(dist.iter()) {
+            res.push(match pos {
+                pos if pos == start => 'S',
+                pos if pos == end => 'E',
+                _ => char::from(b'z' - (d / step).min(25) as u8),
+            });
+            if pos.1 == width - 1 {
+                res.push('\n');
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day12::solve(SAMPLE), Ok((31.into(), 29.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(12).read().expect("cannot read input");
+        assert_eq!(Day12::solve(&input), Ok((456.into(), 454.into())));
+    }
+}
@@ -0,0 +1,346 @@
+use std::{cmp::Ordering, collections::VecDeque, fmt::Display, str::FromStr};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Element {
+    List(Vec<Element>),
+    Integer(usize),
+}
+
+use Element::{Integer, List};
+
+// comparing, printing and dropping packets recurse into the nested lists
+const MAX_DEPTH: usize = 256;
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stack: VecDeque<Vec<Element>> = VecDeque::new();
+        let mut chars = s.char_indices().peekable();
+        let elem = loop {
+            match chars.peek().copied() {
+                Some((i, '[')) => {
+                    chars.next(); // consume
+                    if stack.len() == MAX_DEPTH {
+                        return Err(ParseError::new(i, "[", "lists nested too deeply"));
+                    }
+                    // start a new list
+                    stack.push_front(vec![]);
+                }
+                Some((i, ']')) => {
+                    chars.next(); // consume
+                                  // close current list
+                    let list = stack
+                        .pop_front()
+                        .ok_or_else(|| ParseError::new(i, "]", "closing non-existent list"))?;
+                    match stack.front_mut() {
+                        Some(parent) => parent.push(List(list)),
+                        None => match chars.peek() {
+                            None => break List(list),
+                            Some(&(j, _)) => {
+                                return Err(ParseError::new(
+                                    j,
+                                    &s[j..],
+                                    "found trailing chars after top-level list",
+                                ))
+                            }
+                        },
+                    }
+                }
+                Some((i, n)) if n.is_ascii_digit() => {
+                    // read the full number
+                    let mut end = i;
+                    while let Some((j, ch)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = j + ch.len_utf8();
+                    }
+                    // parse it
+                    let n: usize = s[i..end]
+                        .parse()
+                        .map_err(|e| ParseError::new(i, &s[i..end], e))?;
+                    // append to current list
+                    match stack.front_mut() {
+                        Some(list) => list.push(Integer(n)),
+                        None => {
+                            return Err(ParseError::new(
+                                i,
+                                &s[i..end],
+                                "the outermost element should be a list",
+                            ))
+                        }
+                    };
+                }
+                Some((_, ',')) | Some((_, ' ')) => {
+                    chars.next(); // consume
+                }
+                Some((i, c)) => {
+                    return Err(ParseError::new(
+                        i,
+                        &s[i..i + c.len_utf8()],
+                        "unexpected char",
+                    ))
+                }
+                None => {
+                    return Err(ParseError::new(
+                        s.len(),
+                        "",
+                        "input terminated unexpectedly",
+                    ))
+                }
+            }
+        };
+        Ok(elem)
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Integer(n) => write!(f, "{}", n),
+            List(list) => {
+                write!(f, "[")?;
+                for (i, elem) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", elem)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Integer(l), Integer(r)) => l.cmp(r),
+            (List(l), List(r)) => {
+                let mut l = l.iter();
+                let mut r = r.iter();
+                loop {
+                    let l = l.next();
+                    let r = r.next();
+                    match (l, r) {
+                        (None, Some(_)) => break Ordering::Less,
+                        (Some(_), None) => break Ordering::Greater,
+                        (Some(l), Some(r)) => match l.cmp(r) {
+                            Ordering::Less => break Ordering::Less,
+                            Ordering::Equal => continue,
+                            Ordering::Greater => break Ordering::Greater,
+                        },
+                        (None, None) => break Ordering::Equal,
+                    }
+                }
+            }
+            (Integer(l), r) => List(vec![Integer(*l)]).cmp(r),
+            (l, Integer(r)) => l.cmp(&List(vec![Integer(*r)])),
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    // pairs of packets
+    type Input = Vec<(Element, Element)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(Self::DAY, input);
+        let mut pairs: Vec<(Element, Element)> = Vec::new();
+
+        // next packet
+        let packet = |l: Option<parse::Line>| {
+            let l = l.ok_or_else(|| ParseError::input(Self::DAY, "missing packet"))?;
+            l.text.parse::<Element>().map_err(|e| l.locate(l.text, e))
+        };
+
+        loop {
+            let pack1 = packet(lines.next())?;
+            let pack2 = packet(lines.next())?;
+
+            pairs.push((pack1, pack2));
+
+            match lines.next() {
+                None => break,
+                Some(l) if l.text.is_empty() => (),
+                Some(l) => return Err(l.error(l.text, "expected an empty line")),
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        let in_order: usize = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (pack1, pack2))| pack1 < pack2)
+            .map(|(i, _)| i + 1)
+            .sum();
+        in_order.into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        let mut packets: Vec<Element> = Vec::new();
+        for (pack1, pack2) in pairs {
+            packets.push(pack1.clone());
+            packets.push(pack2.clone());
+        }
+
+        let decode_key = {
+            let div_2 = List(vec![List(vec![Integer(2)])]);
+            let div_6 = List(vec![List(vec![Integer(6)])]);
+
+            packets.push(div_2.clone());
+            packets.push(div_6.clone());
+
+            packets.sort();
+
+            let pos_2 = packets
+                .iter()
+                .position(|p| *p == div_2)
+                .expect("cannot find marker 2")
+                + 1;
+            let pos_6 = packets
+                .iter()
+                .position(|p| *p == div_6)
+                .expect("cannot find marker 6")
+                + 1;
+
+            pos_2 * pos_6
+        };
+        decode_key.into()
+    }
+}
+
+fn random_packet(rng: &mut Rng, depth: usize) -> Element {
+    let len = rng.below(5);
+    List(
+        (0..len)
+            .map(|_| {
+                if depth < 4 && rng.chance(0.3) {
+                    random_packet(rng, depth + 1)
+                } else {
+                    Integer(rng.below(11))
+                }
+            })
+            .collect(),
+    )
+}
+
+// changes a number or drops the end of a list, so that pairs are alike
+fn tweak(rng: &mut Rng, packet: &mut Element) {
+    match packet {
+        Integer(n) => *n = rng.below(11),
+        List(list) if list.is_empty() || rng.chance(0.2) => {
+            list.truncate(rng.below(list.len() + 1))
+        }
+        List(list) => {
+            let i = rng.below(list.len());
+            tweak(rng, &mut list[i]);
+        }
+    }
+}
+
+// `size` pairs of packets
+impl Generator for Day13 {
+    const SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let left = random_packet(rng, 0);
+                let mut right = left.clone();
+                if rng.chance(0.5) {
+                    tweak(rng, &mut right);
+                } else {
+                    right = random_packet(rng, 0);
+                }
+                format!("{}\n{}\n", left, right)
+            })
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day13::solve(SAMPLE), Ok((13.into(), 140.into())));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = Day13::parse("[1,[2,x]]\n[1]").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (13, 1, 7));
+        assert_eq!(err.text, "x");
+
+        let err = Day13::parse("[1]\n[[2]").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.reason, "input terminated unexpectedly");
+
+        let err = "[1]]".parse::<Element>().unwrap_err();
+        assert_eq!(err.column, 4);
+    }
+
+    // used to overflow the stack
+    #[test]
+    fn deeply_nested() {
+        let packet = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+        assert!(packet.parse::<Element>().is_ok());
+
+        let packet = "[".repeat(100000) + &"]".repeat(100000);
+        let err = packet.parse::<Element>().unwrap_err();
+        assert_eq!(
+            (err.column, err.reason.as_str()),
+            (MAX_DEPTH + 1, "lists nested too deeply")
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(13).read().expect("cannot read input");
+        assert_eq!(Day13::solve(&input), Ok((5196.into(), 22134.into())));
+    }
+}
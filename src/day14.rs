@@ -0,0 +1,309 @@
+use std::ops::{Index, IndexMut, RangeInclusive};
+
+use crate::{
+    frames::{self, Paint, Rgb},
+    gen::{Generator, Rng},
+    grid::{Grid, Pos},
+    parse,
+    render::{Glyph, Render, Tile, Tone},
+    trace, Answer, ParseError, Solution,
+};
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub enum Cell {
+    #[default]
+    Air,
+    Rock,
+    Sand,
+    Source,
+}
+
+const AIR: Glyph = Glyph::new('.', Tone::Faint);
+const ROCK: Glyph = Glyph::new('#', Tone::Solid);
+const SAND: Glyph = Glyph::new('o', Tone::Moving);
+const SOURCE: Glyph = Glyph::new('+', Tone::Marked);
+
+impl Tile for Cell {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Self::Air => AIR,
+            Self::Rock => ROCK,
+            Self::Sand => SAND,
+            Self::Source => SOURCE,
+        }
+    }
+
+    fn legend() -> &'static [(Glyph, &'static str)] {
+        &[(ROCK, "rock"), (SAND, "sand"), (SOURCE, "source")]
+    }
+}
+
+impl Paint for Cell {
+    fn paint(&self) -> Rgb {
+        match self {
+            Self::Air => [16, 16, 32],
+            Self::Rock => [110, 100, 90],
+            Self::Sand => [230, 190, 90],
+            Self::Source => [220, 40, 40],
+        }
+    }
+}
+
+// cells are indexed by (x, y), unlike the underlying grid
+#[derive(Clone)]
+pub struct Cave {
+    rock_x_min: usize,
+    rock_x_max: usize,
+    rock_y_min: usize,
+    rock_y_max: usize,
+
+    source: (usize, usize),
+
+    cells: Grid<Cell>,
+}
+
+impl Cave {
+    fn new((width, height): (usize, usize), source: (usize, usize)) -> Self {
+        let mut grid = Self {
+            cells: Grid::new(width, height, Cell::default()),
+            rock_x_min: width,
+            rock_x_max: 0,
+            rock_y_min: height,
+            rock_y_max: 0,
+            source,
+        };
+        grid[(source.0, source.1)] = Cell::Source;
+        grid
+    }
+
+    // the columns below the source that sand can fill
+    fn fill_area(&self) -> RangeInclusive<usize> {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        self.source.0.saturating_sub(height)..=(self.source.0 + height).min(width - 1)
+    }
+
+    fn frame(&self) -> Grid<Rgb> {
+        let xs = self.fill_area();
+        Grid::from_fn(xs.clone().count(), self.cells.height(), |(y, x)| {
+            self[(xs.start() + x, y)].paint()
+        })
+    }
+
+    fn draw_path<'a, I: Iterator<Item = &'a (usize, usize)>>(&mut self, mut path: I) {
+        let mut from = *path.next().expect("path with no first point");
+        for &to in path {
+            // Draw line
+            let (x_0, y_0) = from;
+            let (x_1, y_1) = to;
+            if y_0 == y_1 {
+                let y = y_0;
+                // move horizontally
+                let (x_0, x_1) = if x_0 < x_1 { (x_0, x_1) } else { (x_1, x_0) };
+                for x in x_0..=x_1 {
+                    self[(x, y)] = Cell::Rock;
+                }
+                self.rock_x_min = self.rock_x_min.min(x_0);
+                self.rock_x_max = self.rock_x_max.max(x_1);
+                self.rock_y_min = self.rock_y_min.min(y);
+                self.rock_y_max = self.rock_y_max.max(y);
+            } else if x_0 == x_1 {
+                let x = x_0;
+                let (y_0, y_1) = if y_0 < y_1 { (y_0, y_1) } else { (y_1, y_0) };
+                // move horizontally
+                for y in y_0..=y_1 {
+                    self[(x, y)] = Cell::Rock;
+                }
+                self.rock_y_min = self.rock_y_min.min(y_0);
+                self.rock_y_max = self.rock_y_max.max(y_1);
+                self.rock_x_min = self.rock_x_min.min(x);
+                self.rock_x_max = self.rock_x_max.max(x);
+            }
+            from = to;
+        }
+    }
+
+    fn sand_fall(&mut self) -> Option<(usize, usize)> {
+        if self[self.source] == Cell::Sand {
+            return None; // source occluded
+        }
+        let (mut x, mut y) = self.source;
+        let rest_pos = loop {
+            let y_next = y + 1;
+            if y_next > self.rock_y_max {
+                break None; // fall indefinetly
+            }
+            match self[(x, y_next)] {
+                Cell::Air => y = y_next, // move down
+                Cell::Rock | Cell::Sand => {
+                    let x_next = x - 1;
+                    if x_next < self.rock_x_min {
+                        break None; // fall indefinetly
+                    }
+                    match self[(x_next, y_next)] {
+                        Cell::Air => (x, y) = (x_next, y_next), // move down-left
+                        Cell::Rock | Cell::Sand => {
+                            let x_next = x + 1;
+                            if x_next > self.rock_x_max {
+                                break None; // fall indefinetly
+                            }
+                            match self[(x_next, y_next)] {
+                                Cell::Air => (x, y) = (x_next, y_next), // move down-right
+                                Cell::Rock | Cell::Sand => break Some((x, y)), // come to a rest
+                                Cell::Source => panic!("source"),       // block source
+                            }
+                        }
+                        Cell::Source => panic!("source"),
+                    }
+                }
+                Cell::Source => panic!("source"),
+            }
+        };
+        if let Some(pos) = rest_pos {
+            self[pos] = Cell::Sand;
+        }
+        rest_pos
+    }
+}
+
+impl Index<(usize, usize)> for Cave {
+    type Output = Cell;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.cells[(y, x)]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Cave {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[(y, x)]
+    }
+}
+
+// the area below the source that sand can fill
+impl Render for Cave {
+    fn size(&self) -> (usize, usize) {
+        (self.fill_area().count(), self.cells.height())
+    }
+
+    fn glyph(&self, (y, x): Pos) -> Glyph {
+        self[(self.fill_area().start() + x, y)].glyph()
+    }
+
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        Cell::legend()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    // cave with the rock paths drawn in
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut paths: Vec<Vec<(usize, usize)>> = Vec::new();
+        let mut max_x: usize = 0;
+        let mut max_y: usize = 0;
+
+        // parse
+        for line in parse::lines(Self::DAY, input) {
+            let mut prev: Option<(usize, usize)> = None;
+            let path: Vec<(usize, usize)> = line
+                .text
+                .split(" -> ")
+                .map(|s| {
+                    let (x, y): (usize, usize) = line.scan_part(s, "{x},{y}")?;
+                    if prev.is_some_and(|(px, py)| px != x && py != y) {
+                        return Err(line.error(s, "diagonal segment"));
+                    }
+                    prev = Some((x, y));
+                    max_x = max_x.max(x);
+                    max_y = max_y.max(y);
+                    Ok((x, y))
+                })
+                .collect::<Result<_, _>>()?;
+            paths.push(path);
+        }
+
+        let mut grid = Cave::new(((max_x + 1).max(500 + max_y + 3), max_y + 3), (500, 0));
+
+        for path in paths {
+            grid.draw_path(path.iter());
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        let mut sand_count: usize = 0;
+        while grid.sand_fall().is_some() {
+            sand_count += 1;
+            frames::capture(|| grid.frame());
+        }
+        sand_count.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let (width, height) = (grid.cells.width(), grid.cells.height());
+        grid.draw_path([(0, height - 1), (width - 1, height - 1)].iter());
+
+        let mut sand_count: usize = 0;
+        while grid.sand_fall().is_some() {
+            sand_count += 1;
+            frames::capture(|| grid.frame());
+        }
+        trace!("cave after the sand stopped:\n{}", grid.render());
+        sand_count.into()
+    }
+}
+
+// `size` paths of rock, below the source of the sand
+impl Generator for Day14 {
+    const SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let depth = 10 + size.min(400) as isize;
+        (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(450..=550), rng.range(2..=depth));
+                let mut points = vec![format!("{},{}", x, y)];
+                let horizontal = rng.chance(0.5);
+                for i in 0..rng.below(4) + 1 {
+                    let len = rng.range(1..=8);
+                    if (i % 2 == 0) == horizontal {
+                        x = (x + *rng.choose(&[-len, len])).max(1);
+                    } else {
+                        y = (y + *rng.choose(&[-len, len])).clamp(2, depth);
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day14::solve(SAMPLE), Ok((24.into(), 93.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(14).read().expect("cannot read input");
+        assert_eq!(Day14::solve(&input), Ok((1061.into(), 25055.into())));
+    }
+}
@@ -0,0 +1,383 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    parse::{self, Captures, FromCaptures},
+    trace, Answer, ParseError, Solution,
+};
+
+const LINE: isize = 2000000;
+const BOUND: RangeInclusive<isize> = RangeInclusive::new(0, 4000000);
+
+pub struct Sensor {
+    sensor: (isize, isize),
+    beacon: (isize, isize),
+    radius: usize,
+}
+
+impl Sensor {
+    fn new(sensor: (isize, isize), beacon: (isize, isize)) -> Self {
+        let radius = sensor.0.abs_diff(beacon.0) + sensor.1.abs_diff(beacon.1);
+        Self {
+            sensor,
+            beacon,
+            radius,
+        }
+    }
+
+    fn outer_circle(&self) -> SensorOuterIter {
+        SensorOuterIter::new(self)
+    }
+}
+
+// sensor x, y and beacon x, y
+impl FromCaptures for Sensor {
+    fn from_captures(captures: &mut Captures<'_>) -> Result<Self, ParseError> {
+        Ok(Sensor::new(
+            (captures.take()?, captures.take()?),
+            (captures.take()?, captures.take()?),
+        ))
+    }
+}
+
+// iterate over the exterior border of the sensor (radius + 1)
+struct SensorOuterIter {
+    center: (isize, isize),
+    pos: (isize, isize),
+}
+
+impl SensorOuterIter {
+    fn new(sensor: &Sensor) -> Self {
+        Self {
+            center: sensor.sensor,
+            pos: (
+                sensor.sensor.0 + sensor.radius as isize + 1,
+                sensor.sensor.1,
+            ),
+        }
+    }
+}
+
+impl Iterator for SensorOuterIter {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.pos;
+        let (cx, cy) = self.center;
+
+        if x > cx && y <= cy {
+            // 1st quadrant
+            self.pos = (x - 1, y - 1);
+        } else if x <= cx && y < cy {
+            // 2nd quadrant
+            self.pos = (x - 1, y + 1);
+        } else if x < cx && y >= cy {
+            // 3rd quadrant
+            self.pos = (x + 1, y + 1);
+        } else if x >= cx && y > cy {
+            // 4th quadrand
+            self.pos = (x + 1, y - 1);
+            if self.pos.1 == cy {
+                return None;
+            }
+        }
+
+        Some(self.pos)
+    }
+}
+
+// count the positions on the given line where there can be no beacon
+fn no_beacon_count(sensors: &[Sensor], line: isize) -> usize {
+    // determine ranges in the radius of the sensors
+    let mut marked_ranges: Vec<RangeInclusive<isize>> = Vec::new();
+    for s in sensors.iter() {
+        let sensor = s.sensor;
+        let radius = s.radius;
+
+        debug!(
+            "Sensor: {:?}, Beacon: {:?}, Radius: {}",
+            s.sensor, s.beacon, s.radius,
+        );
+
+        let dist = line.abs_diff(sensor.1); // distance sensor-line
+        let slack = radius as isize - dist as isize;
+        if slack < 0 {
+            continue; // no intersection
+        }
+
+        marked_ranges.push(RangeInclusive::new(sensor.0 - slack, sensor.0 + slack));
+    }
+    let marked_ranges = merge_ranges(marked_ranges);
+
+    // count the positions with no beacons
+    let mut no_beacon_count: usize = 0;
+    let mut beacons_in_line: Vec<isize> = sensors
+        .iter()
+        .filter_map(|s| {
+            if s.beacon.1 == line {
+                Some(s.beacon.0)
+            } else {
+                None
+            }
+        })
+        .collect();
+    // several sensors can share the same beacon
+    beacons_in_line.sort();
+    beacons_in_line.dedup();
+    for range in marked_ranges {
+        no_beacon_count += range.end().abs_diff(*range.start()) + 1;
+        no_beacon_count -= beacons_in_line.iter().filter(|b| range.contains(b)).count();
+    }
+    no_beacon_count
+}
+
+// fuses overlapping ranges, the result is sorted and disjoint
+fn merge_ranges(mut ranges: Vec<RangeInclusive<isize>>) -> Vec<RangeInclusive<isize>> {
+    ranges.sort_by_key(|r| *r.start());
+
+    let mut merged: Vec<RangeInclusive<isize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start() <= last.end() => {
+                if range.end() > last.end() {
+                    *last = RangeInclusive::new(*last.start(), *range.end());
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+// find the only position within the bound that no sensor covers
+fn find_distress_beacon(sensors: &[Sensor], bound: &RangeInclusive<isize>) -> (isize, isize) {
+    let res = sensors.iter().enumerate().find_map(|(i, sensor)| {
+        trace!("Sensor {}", i);
+        sensor.outer_circle().find_map(|(x, y)| {
+            if !bound.contains(&x) || !bound.contains(&y) {
+                return None;
+            }
+
+            let feasible = sensors.iter().all(|other| {
+                let dist = x.abs_diff(other.sensor.0) + y.abs_diff(other.sensor.1);
+                dist > other.radius
+            });
+            if feasible {
+                debug!("Sensor {}: point {:?} feasible", i, (x, y));
+                Some((x, y))
+            } else {
+                None
+            }
+        })
+    });
+
+    res.unwrap()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: usize = 15;
+
+    type Input = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| line.scan("Sensor at x={}, y={}: closest beacon is at x={}, y={}"))
+            .collect()
+    }
+
+    fn part1(sensors: &Self::Input) -> Answer {
+        no_beacon_count(sensors, LINE).into()
+    }
+
+    fn part2(sensors: &Self::Input) -> Answer {
+        let (x, y) = find_distress_beacon(sensors, &BOUND);
+        let tuning_freq = x * 4000000 + y;
+        tuning_freq.into()
+    }
+}
+
+// `size` sensors, covering every position within the bound except the
+// distress beacon
+impl Generator for Day15 {
+    const SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (lo, hi) = BOUND.into_inner();
+        let beacon = (rng.range(lo..=hi), rng.range(lo..=hi));
+        let dist =
+            |(x1, y1): (isize, isize), (x2, y2): (isize, isize)| x1.abs_diff(x2) + y1.abs_diff(y2);
+        // a sensor with its beacon somewhere on its border
+        let sensor = |rng: &mut Rng, sensor: (isize, isize), radius: isize| {
+            let along = rng.range(0..=radius);
+            let (dx, dy) = *rng.choose(&[(1, 1), (1, -1), (-1, 1), (-1, -1)]);
+            let closest = (sensor.0 + dx * along, sensor.1 + dy * (radius - along));
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0, sensor.1, closest.0, closest.1
+            )
+        };
+
+        // one sensor for each diagonal quadrant around the distress beacon,
+        // each reaching it but for one step
+        let mut sensors: Vec<String> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+            .into_iter()
+            .map(|(dx, dy)| {
+                let reach = |d: isize, p: isize| if d > 0 { hi - p } else { p - lo };
+                let min = reach(dx, beacon.0).max(reach(dy, beacon.1)).max(1);
+                let offset = rng.range(min..=2 * min);
+                sensor(
+                    rng,
+                    (beacon.0 + dx * offset, beacon.1 + dy * offset),
+                    2 * offset - 1,
+                )
+            })
+            .collect();
+        while sensors.len() < size.max(4) {
+            let at = (rng.range(lo..=hi), rng.range(lo..=hi));
+            let limit = dist(at, beacon) as isize - 1;
+            if limit > 0 {
+                let radius = rng.range(limit / 2 + 1..=limit);
+                sensors.push(sensor(rng, at, radius));
+            }
+        }
+        rng.shuffle(&mut sensors);
+        sensors.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn sample() {
+        // the sample is checked on a smaller line and bound
+        let sensors = Day15::parse(SAMPLE).unwrap();
+        assert_eq!(no_beacon_count(&sensors, 10), 26);
+        assert_eq!(find_distress_beacon(&sensors, &(0..=20)), (14, 11));
+    }
+
+    fn covered(sensors: &[Sensor], (x, y): (isize, isize)) -> bool {
+        sensors
+            .iter()
+            .any(|s| x.abs_diff(s.sensor.0) + y.abs_diff(s.sensor.1) <= s.radius)
+    }
+
+    fn random_sensors(rng: &mut Rng, n: usize, span: isize) -> Vec<Sensor> {
+        let mut pos = || (rng.range(-span..=span), rng.range(-span..=span));
+        (0..n).map(|_| Sensor::new(pos(), pos())).collect()
+    }
+
+    #[test]
+    fn merge_ranges_matches_naive() {
+        let mut rng = Rng::new(15);
+        for _ in 0..500 {
+            let ranges: Vec<RangeInclusive<isize>> = (0..rng.below(8))
+                .map(|_| {
+                    let start = rng.range(-20..=20);
+                    start..=start + rng.range(0..=10)
+                })
+                .collect();
+            let merged = merge_ranges(ranges.clone());
+
+            for pair in merged.windows(2) {
+                assert!(
+                    pair[0].end() < pair[1].start(),
+                    "{:?} not sorted and disjoint",
+                    merged
+                );
+            }
+            for x in -25..=35 {
+                let naive = ranges.iter().any(|r| r.contains(&x));
+                assert_eq!(
+                    merged.iter().any(|r| r.contains(&x)),
+                    naive,
+                    "{} in {:?}",
+                    x,
+                    ranges
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn merge_contained_ranges() {
+        // a range inside a later one used to be counted twice
+        assert_eq!(merge_ranges(vec![2..=3, 0..=10]), vec![0..=10]);
+        assert_eq!(merge_ranges(vec![0..=2, 6..=8, 1..=7]), vec![0..=8]);
+        assert_eq!(merge_ranges(vec![4..=5, 0..=2]), vec![0..=2, 4..=5]);
+    }
+
+    #[test]
+    fn beacons_on_the_line() {
+        // the beacon is taken out by its column, not by the line
+        let sensors = [Sensor::new((0, 5), (2, 5))];
+        assert_eq!(no_beacon_count(&sensors, 5), 4);
+        // a beacon shared by two sensors is taken out once
+        let sensors = [Sensor::new((0, 0), (2, 0)), Sensor::new((4, 0), (2, 0))];
+        assert_eq!(no_beacon_count(&sensors, 0), 8);
+    }
+
+    #[test]
+    fn no_beacon_count_matches_naive() {
+        let mut rng = Rng::new(15);
+        for _ in 0..200 {
+            let n = rng.below(6) + 1;
+            let sensors = random_sensors(&mut rng, n, 15);
+            let line = rng.range(-15..=15);
+            let naive = (-80..=80)
+                .filter(|&x| covered(&sensors, (x, line)))
+                .filter(|&x| !sensors.iter().any(|s| s.beacon == (x, line)))
+                .count();
+            assert_eq!(no_beacon_count(&sensors, line), naive);
+        }
+    }
+
+    #[test]
+    fn distress_beacon_matches_naive() {
+        let mut rng = Rng::new(15);
+        let mut checked = 0;
+        while checked < 50 {
+            let n = rng.below(8) + 4;
+            let sensors = random_sensors(&mut rng, n, 12);
+            let free: Vec<(isize, isize)> = (0..=10)
+                .flat_map(|x| (0..=10).map(move |y| (x, y)))
+                .filter(|&pos| !covered(&sensors, pos))
+                .collect();
+            // the puzzle guarantees a single free position
+            if let [pos] = free[..] {
+                assert_eq!(find_distress_beacon(&sensors, &(0..=10)), pos);
+                checked += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(15).read().expect("cannot read input");
+        assert_eq!(
+            Day15::solve(&input),
+            Ok((4919281.into(), 12630143363767_usize.into()))
+        );
+    }
+}
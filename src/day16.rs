@@ -0,0 +1,525 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    parse::{self, Line},
+    search::{Search, SearchProblem, Strategy},
+    Answer, ParseError, Solution,
+};
+
+const TIMESPAN: usize = 30;
+const INITIAL_VALVE: &str = "AA";
+
+#[derive(Debug)]
+pub struct Valve {
+    name: String,
+    flow_rate: usize,
+    neighbors: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::detached(s);
+        let (first, second) = s
+            .split_once("; ")
+            .ok_or_else(|| ParseError::new(0, s, "no separator ;"))?;
+
+        let (name, flow_rate) = line.scan_part(first, "Valve {name} has flow rate={rate}")?;
+
+        let prefix = ["tunnel leads to valve ", "tunnels lead to valves "]
+            .into_iter()
+            .find(|p| second.starts_with(p))
+            .ok_or_else(|| line.error(second, "expected the tunnels"))?;
+        let out_edges = line.scan_part(&second[prefix.len()..], "{valves*, }")?;
+
+        Ok(Self {
+            name,
+            flow_rate,
+            neighbors: out_edges,
+        })
+    }
+}
+
+// time, valve and open valves, sorted
+type State = (usize, String, Vec<String>);
+
+#[derive(Debug, Clone)]
+struct SearchNode {
+    t: usize,                 // time
+    valve: String,            // current valve
+    open_valves: Vec<String>, // valves currently open
+    path_gain: usize,
+}
+
+impl SearchNode {
+    fn initial(valve: &Valve) -> Self {
+        Self {
+            t: 0,
+            valve: valve.name.clone(),
+            open_valves: Vec::new(),
+            path_gain: 0,
+        }
+    }
+
+    // all but the gain, which is the same to add for what follows
+    fn state(&self) -> State {
+        let mut open_valves = self.open_valves.clone();
+        open_valves.sort();
+        open_valves.dedup();
+        (self.t, self.valve.clone(), open_valves)
+    }
+
+    fn heuristic(&self, sorted_valves: &[(String, usize)]) -> usize {
+        sorted_valves
+            .iter()
+            .filter(|(n, _)| !self.open_valves.contains(n))
+            .enumerate()
+            .map(|(i, (_, rate))| {
+                let t = self.t + 2 * (i + 1);
+                if t > TIMESPAN {
+                    0
+                } else {
+                    rate * (TIMESPAN - t)
+                }
+            })
+            .sum()
+    }
+
+    fn evaluate(&self, sorted_valves: &[(String, usize)]) -> usize {
+        self.path_gain + self.heuristic(sorted_valves)
+    }
+
+    fn expand(
+        &self,
+        valves: &HashMap<String, Valve>,
+        distances: &HashMap<String, HashMap<String, usize>>,
+    ) -> Vec<SearchNode> {
+        let mut children: Vec<SearchNode> = Vec::new();
+        if self.t >= TIMESPAN {
+            return children;
+        }
+
+        let valve = valves.get(&self.valve).expect("valve not found");
+        let distances = &distances[&valve.name];
+
+        for (dest, dist) in distances.iter() {
+            // action: go to another valve and open it
+            if !self.open_valves.contains(dest) {
+                let dest = valves.get(dest).expect("valve not found");
+                let t = self.t + dist + 1;
+                if t > TIMESPAN {
+                    continue;
+                }
+                let mut open_valves = self.open_valves.clone();
+                open_valves.push(dest.name.clone());
+                let path_gain = self.path_gain + dest.flow_rate * (TIMESPAN - t);
+                let next_node = SearchNode {
+                    t,
+                    valve: dest.name.clone(),
+                    open_valves,
+                    path_gain,
+                };
+
+                children.push(next_node);
+            }
+        }
+        children
+    }
+}
+
+impl Display for SearchNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SearchNode(t: {}, v: {:?}, path: {:?}, pg: {})",
+            self.t, self.valve, self.open_valves, self.path_gain
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct JointSearchNode(SearchNode, SearchNode);
+
+impl JointSearchNode {
+    fn initial(valve: &Valve) -> Self {
+        let node_0 = SearchNode {
+            t: 4,
+            valve: valve.name.clone(),
+            open_valves: Vec::new(),
+            path_gain: 0,
+        };
+        let node_1 = node_0.clone();
+        Self(node_0, node_1)
+    }
+
+    fn path_gain(&self) -> usize {
+        self.0.path_gain + self.1.path_gain
+    }
+
+    fn heuristic(&self, sorted_valves: &[(String, usize)]) -> usize {
+        self.0.heuristic(sorted_valves) + self.1.heuristic(sorted_valves)
+    }
+
+    fn evaluate(&self, sorted_valves: &[(String, usize)]) -> usize {
+        self.path_gain() + self.heuristic(sorted_valves)
+    }
+
+    fn expand(
+        &self,
+        valves: &HashMap<String, Valve>,
+        distances: &HashMap<String, HashMap<String, usize>>,
+    ) -> Vec<JointSearchNode> {
+        let mut children = Vec::new();
+
+        let mut children_0 = self.0.expand(valves, distances);
+        let mut children_1 = self.1.expand(valves, distances);
+
+        // if there is no other option, allow one of the two to stall
+        if children_0.is_empty() && children_1.is_empty() {
+            // terminal state
+            return children;
+        }
+        if children_0.is_empty() {
+            children_0.push(self.0.clone());
+        }
+        if children_1.is_empty() {
+            children_1.push(self.1.clone());
+        }
+
+        // create a child for each pair
+        for c0 in children_0.iter() {
+            for c1 in children_1.iter() {
+                // don't open the same valve
+                if c0.valve == c1.valve {
+                    continue;
+                }
+
+                // update the list of open valves
+                let mut c0 = c0.clone();
+                let mut c1 = c1.clone();
+                c0.open_valves.push(c1.valve.clone());
+                c1.open_valves.push(c0.valve.clone());
+
+                children.push(Self(c0, c1));
+            }
+        }
+
+        children
+    }
+}
+
+impl Display for JointSearchNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "JointSearchNode\n\t{}\n\t{})", self.0, self.1)
+    }
+}
+
+fn shortest_paths(valves: &HashMap<String, Valve>) -> HashMap<String, HashMap<String, usize>> {
+    let mut dist = HashMap::new();
+
+    // init
+    for valve in valves.values() {
+        let distances_v: &mut HashMap<String, usize> = dist
+            .entry(valve.name.clone())
+            .or_insert_with(|| [(valve.name.clone(), 0)].into_iter().collect());
+        for dest in valve.neighbors.iter() {
+            distances_v.insert(dest.clone(), 1);
+        }
+    }
+
+    for k in valves.keys() {
+        for i in valves.keys() {
+            for j in valves.keys() {
+                let Some(&dist_ik) = dist[i].get(k) else {
+                    continue;
+                };
+                let Some(&dist_kj) = dist[k].get(j) else {
+                    continue;
+                };
+                let &dist_ij = dist[i].get(j).unwrap_or(&usize::MAX);
+                if dist_ij > dist_ik + dist_kj {
+                    match dist.get_mut(i).unwrap().entry(j.clone()) {
+                        Entry::Occupied(mut e) => {
+                            e.insert(dist_ik + dist_kj);
+                        }
+                        Entry::Vacant(e) => {
+                            e.insert(dist_ik + dist_kj);
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+pub struct Network {
+    valves: HashMap<String, Valve>,
+    // valves with a positive flow rate, highest first
+    sorted_valves: Vec<(String, usize)>,
+    // shortest distances between the interesting valves
+    distances: HashMap<String, HashMap<String, usize>>,
+}
+
+// opening valves on our own
+struct Alone<'a>(&'a Network);
+
+impl SearchProblem for Alone<'_> {
+    type Node = SearchNode;
+    type Key = State;
+
+    fn start(&self) -> SearchNode {
+        SearchNode::initial(&self.0.valves[INITIAL_VALVE])
+    }
+
+    fn key(&self, node: &SearchNode) -> State {
+        node.state()
+    }
+
+    fn expand(&self, node: &SearchNode) -> Vec<(SearchNode, usize)> {
+        let children = node.expand(&self.0.valves, &self.0.distances);
+        children.into_iter().map(|child| (child, 1)).collect()
+    }
+
+    fn value(&self, node: &SearchNode) -> usize {
+        node.path_gain
+    }
+
+    fn bound(&self, node: &SearchNode) -> usize {
+        node.evaluate(&self.0.sorted_valves)
+    }
+}
+
+// opening valves together with an elephant, after teaching it
+struct WithElephant<'a>(&'a Network);
+
+impl SearchProblem for WithElephant<'_> {
+    type Node = JointSearchNode;
+    type Key = (State, State);
+
+    fn start(&self) -> JointSearchNode {
+        JointSearchNode::initial(&self.0.valves[INITIAL_VALVE])
+    }
+
+    // either of the two can be where the other is
+    fn key(&self, node: &JointSearchNode) -> (State, State) {
+        let (a, b) = (node.0.state(), node.1.state());
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    fn expand(&self, node: &JointSearchNode) -> Vec<(JointSearchNode, usize)> {
+        let children = node.expand(&self.0.valves, &self.0.distances);
+        children.into_iter().map(|child| (child, 1)).collect()
+    }
+
+    fn value(&self, node: &JointSearchNode) -> usize {
+        node.path_gain()
+    }
+
+    fn bound(&self, node: &JointSearchNode) -> usize {
+        node.evaluate(&self.0.sorted_valves)
+    }
+}
+
+fn max_pressure<P: SearchProblem>(problem: &P) -> usize
+where
+    P::Node: Display,
+{
+    // nodes reaching the same valves at the same time differ in the pressure
+    // released so far, only the best ones are expanded
+    let outcome = Search::new(problem).run(Strategy::BranchAndBound);
+    let best = outcome.found.expect("no initial node");
+    debug!("Max {} for {} ({})", best.score, best.node, outcome.stats);
+    best.score
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let parsed: Vec<(parse::Line, Valve)> = parse::lines(Self::DAY, input)
+            .map(|l| {
+                let valve: Valve = l.text.parse().map_err(|e| l.locate(l.text, e))?;
+                Ok((l, valve))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        // tunnels must lead somewhere
+        for (l, valve) in parsed.iter() {
+            let known = |n: &String| parsed.iter().any(|(_, v)| v.name == *n);
+            if let Some(n) = valve.neighbors.iter().find(|n| !known(n)) {
+                let at = l.text.rfind(n.as_str()).unwrap_or(0);
+                return Err(l.error(&l.text[at..at + n.len()], "unknown valve"));
+            }
+        }
+        let valves: HashMap<String, Valve> = parsed
+            .into_iter()
+            .map(|(_, valve)| (valve.name.clone(), valve))
+            .collect();
+        if !valves.contains_key(INITIAL_VALVE) {
+            return Err(ParseError::input(
+                Self::DAY,
+                format!("missing valve {}", INITIAL_VALVE),
+            ));
+        }
+
+        let mut sorted_valves: Vec<(String, usize)> = valves
+            .values()
+            .filter(|v| v.flow_rate > 0)
+            .map(|v| (v.name.clone(), v.flow_rate))
+            .collect();
+        sorted_valves.sort_by_key(|(_, rate)| Reverse(*rate));
+
+        // find shortest paths from each interesting source
+        let mut distances = shortest_paths(&valves);
+        distances.retain(|i, ds| {
+            let valve = &valves[i];
+            if valve.flow_rate > 0 || valve.name == INITIAL_VALVE {
+                ds.retain(|j, _| j != i && valves[j].flow_rate > 0);
+                true
+            } else {
+                false
+            }
+        });
+
+        Ok(Network {
+            valves,
+            sorted_valves,
+            distances,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Answer {
+        max_pressure(&Alone(network)).into()
+    }
+
+    fn part2(network: &Self::Input) -> Answer {
+        max_pressure(&WithElephant(network)).into()
+    }
+}
+
+// `size` valves: the initial one and up to 15 with some flow, linked by
+// corridors of broken valves like in the real inputs
+impl Generator for Day16 {
+    const SIZE: usize = 60;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.clamp(2, 26 * 26);
+        let mut names = vec![INITIAL_VALVE.to_string()];
+        while names.len() < n {
+            let name: String = (0..2)
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        // the valves worth opening, a random tree of them with a few loops
+        let rooms = (n / 4).clamp(2, 16);
+        let mut links: Vec<(usize, usize)> = (1..rooms).map(|i| (i, rng.below(i))).collect();
+        for _ in 0..rooms / 2 {
+            let (a, b) = (rng.below(rooms), rng.below(rooms));
+            if a != b && !links.contains(&(a, b)) && !links.contains(&(b, a)) {
+                links.push((a, b));
+            }
+        }
+        // the remaining valves lengthen the links
+        let mut corridors: Vec<Vec<usize>> = vec![Vec::new(); links.len()];
+        for i in rooms..n {
+            corridors[rng.below(links.len())].push(i);
+        }
+
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (&(a, b), corridor) in links.iter().zip(&corridors) {
+            let path: Vec<usize> = [a]
+                .into_iter()
+                .chain(corridor.iter().copied())
+                .chain([b])
+                .collect();
+            for pair in path.windows(2) {
+                tunnels[pair[0]].push(pair[1]);
+                tunnels[pair[1]].push(pair[0]);
+            }
+        }
+
+        let mut lines: Vec<String> = (0..n)
+            .map(|i| {
+                let rate = if (1..rooms).contains(&i) {
+                    rng.range(3..=25)
+                } else {
+                    0
+                };
+                let others: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+                let tunnels = match others[..] {
+                    [other] => format!("tunnel leads to valve {}", other),
+                    _ => format!("tunnels lead to valves {}", others.join(", ")),
+                };
+                format!("Valve {} has flow rate={}; {}\n", names[i], rate, tunnels)
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+        lines.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day16::solve(SAMPLE), Ok((1651.into(), 1707.into())));
+    }
+
+    #[test]
+    fn malformed() {
+        let input = SAMPLE.replace("rate=13", "rate=x");
+        let err = Day16::parse(&input).err().unwrap();
+        assert_eq!((err.day, err.line, err.column), (16, 2, 24));
+        assert_eq!(
+            (err.text.as_str(), err.reason.as_str()),
+            ("x", "invalid rate: invalid digit found in string")
+        );
+
+        let input = SAMPLE.replace("valves CC, AA", "valves CC, ZZ");
+        let err = Day16::parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 55));
+        assert_eq!(err.text, "ZZ");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(16).read().expect("cannot read input");
+        assert_eq!(Day16::solve(&input), Ok((2253.into(), 2838.into())));
+    }
+}
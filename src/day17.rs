@@ -0,0 +1,470 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    cycle, debug,
+    frames::{self, Rgb},
+    gen::{Generator, Rng},
+    grid::{Grid, Pos},
+    parse,
+    render::{Glyph, Render, Tone},
+    trace, Answer, ParseError, Solution,
+};
+
+const WIDTH: usize = 7;
+const SPAWN_X: usize = 2;
+const SPAWN_Y: usize = 3;
+const TARGET_PART_1: usize = 2022;
+const TARGET_PART_2: usize = 1000000000000;
+// pieces dropped at most while looking for the tower to repeat
+const CYCLE_LIMIT: usize = 100_000;
+// rows below the top of the chamber shown in frames
+const FRAME_ROWS: usize = 40;
+
+// rows are counted from the bottom
+#[derive(Debug, Clone)]
+struct Chamber {
+    cells: Grid<bool>,
+}
+
+impl Chamber {
+    fn new() -> Self {
+        Self {
+            cells: Grid::new(WIDTH, 0, false),
+        }
+    }
+
+    // relative
+    fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    fn fit(&mut self, target: usize) {
+        if target < self.height() {
+            return;
+        }
+        self.cells.resize_rows(target + 1, false);
+    }
+
+    // drops the rows that falling pieces cannot reach anymore, returns how many
+    fn trim_bottom(&mut self) -> usize {
+        let height = self.height();
+        if height == 0 {
+            return 0;
+        }
+
+        // flood the free cells from the top row
+        let mut visited = Grid::new(WIDTH, height, false);
+        let mut stack: Vec<(usize, usize)> = self
+            .cells
+            .row_positions(height - 1)
+            .filter(|&pos| !self.cells[pos])
+            .collect();
+        let mut y_min = height;
+        while let Some(pos) = stack.pop() {
+            if visited[pos] {
+                continue;
+            }
+            visited[pos] = true;
+            y_min = y_min.min(pos.0);
+            stack.extend(self.cells.neighbours(pos).filter(|&succ| !self.cells[succ]));
+        }
+
+        // the row below the lowest free cell can still be hit
+        let y = y_min.saturating_sub(1);
+        if y > 0 {
+            self.cells.drain_rows(0..y);
+        }
+        y
+    }
+
+    fn collides(&self, x: isize, y: isize) -> bool {
+        if x < 0 || x >= WIDTH as isize || y < 0 {
+            return true;
+        }
+        let x = x as usize;
+        let y = y as usize;
+
+        if y >= self.height() {
+            false
+        } else {
+            self[(x, y)]
+        }
+    }
+
+    fn piece_collides(&self, piece: &[(usize, usize)], dx: isize, dy: isize) -> bool {
+        piece
+            .iter()
+            .any(|(x, y)| self.collides(*x as isize + dx, *y as isize + dy))
+    }
+
+    // one bit per cell of each row, from the bottom
+    fn packed_rows(&self) -> Vec<u8> {
+        self.cells
+            .rows()
+            .map(|row| row.iter().fold(0, |bits, &rock| bits << 1 | rock as u8))
+            .collect()
+    }
+
+    fn place(&mut self, piece: &[(usize, usize)]) {
+        for (x, y) in piece.iter() {
+            let x = *x;
+            let y = *y;
+            self.fit(y);
+            self[(x, y)] = true;
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Chamber {
+    type Output = bool;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.cells[(y, x)]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Chamber {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[(y, x)]
+    }
+}
+
+const ROCK: Glyph = Glyph::new('#', Tone::Solid);
+const AIR: Glyph = Glyph::new('.', Tone::Faint);
+
+// the rows from the top, between the walls and above the floor
+impl Render for Chamber {
+    fn size(&self) -> (usize, usize) {
+        (WIDTH + 2, self.height() + 1)
+    }
+
+    fn glyph(&self, (i, j): Pos) -> Glyph {
+        let height = self.height();
+        match (i == height, j == 0 || j == WIDTH + 1) {
+            (true, true) => Glyph::new('+', Tone::Solid),
+            (true, false) => Glyph::new('-', Tone::Solid),
+            (false, true) => Glyph::new('|', Tone::Solid),
+            (false, false) if self.cells[(height - 1 - i, j - 1)] => ROCK,
+            (false, false) => AIR,
+        }
+    }
+
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        &[(ROCK, "rock at rest")]
+    }
+}
+
+// the anchor is the bottom-left corner
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum Piece {
+    Horizontal,
+    Cross,
+    Angle,
+    Vertical,
+    Square,
+}
+
+impl Piece {
+    fn cells(&self) -> &'static [(usize, usize)] {
+        match self {
+            Piece::Horizontal => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            Piece::Vertical => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            Piece::Cross => &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            Piece::Angle => &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Piece::Square => &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        }
+    }
+
+    fn for_id(id: usize) -> Self {
+        match id % 5 {
+            0 => Self::Horizontal,
+            1 => Self::Cross,
+            2 => Self::Angle,
+            3 => Self::Vertical,
+            4 => Self::Square,
+            _ => panic!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    piece: Piece,
+    seq_offset: usize,
+    chamber: Chamber,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            piece: Piece::for_id(0),
+            seq_offset: 0,
+            chamber: Chamber::new(),
+        }
+    }
+
+    fn spawn(&self) -> Vec<(usize, usize)> {
+        let mut cells = self.piece.cells().to_vec();
+        cells.iter_mut().for_each(|(x, y)| {
+            *x += SPAWN_X;
+            *y += self.chamber.height() + SPAWN_Y;
+        });
+        cells
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "State(piece: {}, seq: {})",
+            self.piece as usize, self.seq_offset
+        )
+    }
+}
+
+// top of the chamber with the falling piece, between the walls
+fn frame(chamber: &Chamber, piece: &[(usize, usize)]) -> Grid<Rgb> {
+    const AIR: Rgb = [16, 16, 32];
+    const ROCK: Rgb = [140, 140, 150];
+    const FALLING: Rgb = [240, 160, 40];
+    const WALL: Rgb = [70, 60, 60];
+
+    let top = chamber.height() + SPAWN_Y + 4;
+    Grid::from_fn(WIDTH + 2, FRAME_ROWS, |(i, j)| {
+        let Some(y) = (top - 1).checked_sub(i) else {
+            return WALL;
+        };
+        if j == 0 || j == WIDTH + 1 {
+            return WALL;
+        }
+        let x = j - 1;
+        if piece.contains(&(x, y)) {
+            FALLING
+        } else if y < chamber.height() && chamber[(x, y)] {
+            ROCK
+        } else {
+            AIR
+        }
+    })
+}
+
+// drops the next piece, returns how much higher the tower got
+fn drop_piece(state: &mut State, jets: &[u8]) -> usize {
+    let height = state.chamber.height();
+    let mut piece = state.spawn();
+    loop {
+        frames::capture(|| frame(&state.chamber, &piece));
+
+        // push left/right
+        let dx: isize = match jets[state.seq_offset] {
+            b'<' => -1,
+            b'>' => 1,
+            _ => panic!("invalid move"),
+        };
+        state.seq_offset = (state.seq_offset + 1) % jets.len();
+        if !state.chamber.piece_collides(&piece, dx, 0) {
+            piece.iter_mut().for_each(|(x, _)| {
+                *x = (*x as isize + dx) as usize;
+            });
+        }
+
+        // push down
+        if state.chamber.piece_collides(&piece, 0, -1) {
+            break;
+        }
+        piece.iter_mut().for_each(|(_, y)| {
+            *y -= 1;
+        });
+    }
+
+    // rest
+    state.chamber.place(&piece);
+    let trimmed = state.chamber.trim_bottom();
+    trace!("{} rows trimmed:\n{}", trimmed, state.chamber.render());
+    state.piece = Piece::for_id(state.piece as usize + 1);
+    state.chamber.height() + trimmed - height
+}
+
+// height of the tower after the given number of pieces have come to rest,
+// dropping at most `limit` of them to find where it repeats
+fn tower_height(jets: &str, target: usize, limit: usize) -> Result<usize, String> {
+    // the trimmed chamber holds all that matters for the next pieces
+    let key = |state: &State| (state.piece, state.seq_offset, state.chamber.packed_rows());
+    let history = cycle::detect(State::new(), target.min(limit), key, |state| {
+        drop_piece(state, jets.as_bytes())
+    });
+    if let Some(cycle) = history.cycle() {
+        debug!(
+            "the tower repeats every {} pieces after {}",
+            cycle.period, cycle.start
+        );
+    }
+    history
+        .at(target)
+        .ok_or_else(|| format!("the tower does not repeat within {} pieces", limit))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: usize = 17;
+
+    // sequence of jet pushes
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some(line) = parse::lines(Self::DAY, input).next() else {
+            return Err(ParseError::input(Self::DAY, "empty input"));
+        };
+        if let Some(i) = line.text.find(|c| c != '<' && c != '>') {
+            return Err(line.error(&line.text[i..i + 1], "invalid jet"));
+        }
+        if line.text.is_empty() {
+            return Err(line.missing("jets"));
+        }
+        Ok(line.text.to_string())
+    }
+
+    fn part1(jets: &Self::Input) -> Answer {
+        tower_height(jets, TARGET_PART_1, CYCLE_LIMIT).into()
+    }
+
+    fn part2(jets: &Self::Input) -> Answer {
+        tower_height(jets, TARGET_PART_2, CYCLE_LIMIT).into()
+    }
+}
+
+// `size` jets, at least a thousand: with fewer of them rocks may never be pushed
+// against a wall, and the tower never repeats
+impl Generator for Day17 {
+    const SIZE: usize = 10091;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let jets: String = (0..size.max(1000))
+            .map(|_| *rng.choose(&['<', '>']))
+            .collect();
+        jets + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+>>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn sample() {
+        assert_eq!(
+            Day17::solve(SAMPLE),
+            Ok((3068.into(), 1514285714288_usize.into()))
+        );
+    }
+
+    // drops every piece in a chamber that is never trimmed
+    fn naive_height(jets: &str, target: usize) -> usize {
+        let mut rows: Vec<[bool; WIDTH]> = Vec::new();
+        let mut jets = jets.bytes().cycle();
+        let free = |rows: &Vec<[bool; WIDTH]>, cells: &[(isize, isize)]| {
+            cells.iter().all(|&(x, y)| {
+                (0..WIDTH as isize).contains(&x)
+                    && y >= 0
+                    && rows.get(y as usize).is_none_or(|row| !row[x as usize])
+            })
+        };
+
+        for id in 0..target {
+            let (x0, y0) = (SPAWN_X as isize, (rows.len() + SPAWN_Y) as isize);
+            let mut cells: Vec<(isize, isize)> = Piece::for_id(id)
+                .cells()
+                .iter()
+                .map(|&(x, y)| (x0 + x as isize, y0 + y as isize))
+                .collect();
+            loop {
+                let dx = if jets.next() == Some(b'<') { -1 } else { 1 };
+                let pushed: Vec<_> = cells.iter().map(|&(x, y)| (x + dx, y)).collect();
+                if free(&rows, &pushed) {
+                    cells = pushed;
+                }
+                let fallen: Vec<_> = cells.iter().map(|&(x, y)| (x, y - 1)).collect();
+                if !free(&rows, &fallen) {
+                    break;
+                }
+                cells = fallen;
+            }
+            for (x, y) in cells {
+                if y as usize >= rows.len() {
+                    rows.resize(y as usize + 1, [false; WIDTH]);
+                }
+                rows[y as usize][x as usize] = true;
+            }
+        }
+        rows.len()
+    }
+
+    #[test]
+    fn shortcut_matches_naive() {
+        let mut rng = Rng::new(17);
+        for _ in 0..30 {
+            let len = rng.below(50) + 1;
+            let jets: String = (0..len).map(|_| *rng.choose(&['<', '>'])).collect();
+            let target = rng.below(1500) + 1;
+            assert_eq!(
+                tower_height(&jets, target, CYCLE_LIMIT),
+                Ok(naive_height(&jets, target)),
+                "{} after {}",
+                jets,
+                target
+            );
+        }
+    }
+
+    fn chamber(rows: &[&str]) -> Chamber {
+        let mut chamber = Chamber::new();
+        chamber.fit(rows.len() - 1);
+        for (y, row) in rows.iter().rev().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                chamber[(x, y)] = c == '#';
+            }
+        }
+        chamber
+    }
+
+    #[test]
+    fn trim_bottom() {
+        // the pocket under the overhang can still be reached from the side
+        let mut pocket = chamber(&[".......", "######.", "#......", "#######"]);
+        assert_eq!(pocket.trim_bottom(), 0);
+        assert_eq!(pocket.height(), 4);
+        // nothing gets past a full row
+        let mut full = chamber(&[".......", "#######", "#......", "#######"]);
+        assert_eq!(full.trim_bottom(), 2);
+        assert_eq!(full.packed_rows(), vec![0b1111111, 0]);
+    }
+
+    #[test]
+    fn limit() {
+        // the sample repeats every 35 pieces
+        assert_eq!(tower_height(SAMPLE, 5, 10), Ok(naive_height(SAMPLE, 5)));
+        assert_eq!(
+            tower_height(SAMPLE, 2022, 10),
+            Err("the tower does not repeat within 10 pieces".to_string())
+        );
+        assert_eq!(tower_height(SAMPLE, 2022, 100), Ok(3068));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(17).read().expect("cannot read input");
+        assert_eq!(
+            Day17::solve(&input),
+            Ok((3130.into(), 1556521739139_usize.into()))
+        );
+    }
+}
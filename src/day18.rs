@@ -0,0 +1,395 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    gen::{Generator, Rng},
+    geometry::Point3,
+    parse, Answer, ParseError, Solution,
+};
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Face {
+    N = 0,
+    S = 1,
+    E = 2,
+    W = 3,
+    U = 4,
+    D = 5,
+}
+
+type AdjacentFaceCollection = &'static [&'static [((isize, isize, isize), Face)]];
+
+impl Face {
+    fn adjacent_faces(&self) -> AdjacentFaceCollection {
+        match self {
+            // 90* angle, 180* angle, 270* angle
+            Self::N => &[
+                &[
+                    ((1, 1, 0), Self::W),
+                    ((1, 0, 0), Self::N),
+                    ((0, 0, 0), Self::E),
+                ],
+                &[
+                    ((-1, 1, 0), Self::E),
+                    ((-1, 0, 0), Self::N),
+                    ((0, 0, 0), Self::W),
+                ],
+                &[
+                    ((0, 1, 1), Self::D),
+                    ((0, 0, 1), Self::N),
+                    ((0, 0, 0), Self::U),
+                ],
+                &[
+                    ((0, 1, -1), Self::U),
+                    ((0, 0, -1), Self::N),
+                    ((0, 0, 0), Self::D),
+                ],
+            ],
+            Self::S => &[
+                &[
+                    ((1, -1, 0), Self::W),
+                    ((1, 0, 0), Self::S),
+                    ((0, 0, 0), Self::E),
+                ],
+                &[
+                    ((-1, -1, 0), Self::E),
+                    ((-1, 0, 0), Self::S),
+                    ((0, 0, 0), Self::W),
+                ],
+                &[
+                    ((0, -1, 1), Self::D),
+                    ((0, 0, 1), Self::S),
+                    ((0, 0, 0), Self::U),
+                ],
+                &[
+                    ((0, -1, -1), Self::U),
+                    ((0, 0, -1), Self::S),
+                    ((0, 0, 0), Self::D),
+                ],
+            ],
+            Self::E => &[
+                &[
+                    ((1, 1, 0), Self::S),
+                    ((0, 1, 0), Self::E),
+                    ((0, 0, 0), Self::N),
+                ],
+                &[
+                    ((1, -1, 0), Self::N),
+                    ((0, -1, 0), Self::E),
+                    ((0, 0, 0), Self::S),
+                ],
+                &[
+                    ((1, 0, 1), Self::D),
+                    ((0, 0, 1), Self::E),
+                    ((0, 0, 0), Self::U),
+                ],
+                &[
+                    ((1, 0, -1), Self::U),
+                    ((0, 0, -1), Self::E),
+                    ((0, 0, 0), Self::D),
+                ],
+            ],
+            Self::W => &[
+                &[
+                    ((-1, 1, 0), Self::S),
+                    ((0, 1, 0), Self::W),
+                    ((0, 0, 0), Self::N),
+                ],
+                &[
+                    ((-1, -1, 0), Self::N),
+                    ((0, -1, 0), Self::W),
+                    ((0, 0, 0), Self::S),
+                ],
+                &[
+                    ((-1, 0, 1), Self::D),
+                    ((0, 0, 1), Self::W),
+                    ((0, 0, 0), Self::U),
+                ],
+                &[
+                    ((-1, 0, -1), Self::U),
+                    ((0, 0, -1), Self::W),
+                    ((0, 0, 0), Self::D),
+                ],
+            ],
+            Self::U => &[
+                &[
+                    ((1, 0, 1), Self::W),
+                    ((1, 0, 0), Self::U),
+                    ((0, 0, 0), Self::E),
+                ],
+                &[
+                    ((-1, 0, 1), Self::E),
+                    ((-1, 0, 0), Self::U),
+                    ((0, 0, 0), Self::W),
+                ],
+                &[
+                    ((0, 1, 1), Self::S),
+                    ((0, 1, 0), Self::U),
+                    ((0, 0, 0), Self::N),
+                ],
+                &[
+                    ((0, -1, 1), Self::N),
+                    ((0, -1, 0), Self::U),
+                    ((0, 0, 0), Self::S),
+                ],
+            ],
+            Self::D => &[
+                &[
+                    ((1, 0, -1), Self::W),
+                    ((1, 0, 0), Self::D),
+                    ((0, 0, 0), Self::E),
+                ],
+                &[
+                    ((-1, 0, -1), Self::E),
+                    ((-1, 0, 0), Self::D),
+                    ((0, 0, 0), Self::W),
+                ],
+                &[
+                    ((0, 1, -1), Self::S),
+                    ((0, 1, 0), Self::D),
+                    ((0, 0, 0), Self::N),
+                ],
+                &[
+                    ((0, -1, -1), Self::N),
+                    ((0, -1, 0), Self::D),
+                    ((0, 0, 0), Self::S),
+                ],
+            ],
+        }
+    }
+}
+
+type Cube = Point3<isize>;
+
+type FacesIndex = HashSet<(Cube, Face)>;
+
+fn open_faces(cubes: &[Cube]) -> FacesIndex {
+    let mut open_faces = FacesIndex::new();
+
+    // iterate over cubes
+    for &cube in cubes {
+        // check each face: occlude opposite face or mark visible
+        for (offset, face, opposite) in [
+            ((0, 1, 0), Face::N, Face::S),
+            ((0, -1, 0), Face::S, Face::N),
+            ((1, 0, 0), Face::E, Face::W),
+            ((-1, 0, 0), Face::W, Face::E),
+            ((0, 0, 1), Face::U, Face::D),
+            ((0, 0, -1), Face::D, Face::U),
+        ] {
+            if !open_faces.remove(&(cube + offset.into(), opposite)) {
+                open_faces.insert((cube, face));
+            }
+        }
+    }
+    open_faces
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: usize = 18;
+
+    type Input = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| {
+                let mut it = line.text.split(',');
+                let mut coord = || {
+                    let tok = it.next().ok_or_else(|| line.missing("coordinate"))?;
+                    line.parse::<isize>(tok)
+                };
+                let x = coord()?;
+                let y = coord()?;
+                let z = coord()?;
+                if let Some(tok) = it.next() {
+                    return Err(line.error(tok, "too many coordinates"));
+                }
+                Ok(Cube::new(x, y, z))
+            })
+            .collect()
+    }
+
+    fn part1(cubes: &Self::Input) -> Answer {
+        let open_faces_count: usize = open_faces(cubes).len();
+        open_faces_count.into()
+    }
+
+    fn part2(cubes: &Self::Input) -> Answer {
+        let open_faces = open_faces(cubes);
+
+        // find an external face to start from
+        let res = open_faces
+            .iter()
+            .filter(|(_, of)| of == &Face::U)
+            .max_by_key(|(cube, _)| cube.z);
+
+        let Some(&initial) = res else {
+            panic!("No starting point");
+        };
+
+        let mut frontier: VecDeque<(Cube, Face)> = VecDeque::new();
+        let mut expanded: FacesIndex = FacesIndex::new();
+        frontier.push_back(initial);
+
+        while let Some(node) = frontier.pop_front() {
+            if !expanded.insert(node) {
+                continue;
+            }
+
+            // visit adjacent faces
+            let (cube, f) = node;
+
+            for &group in f.adjacent_faces() {
+                // in each group, visit the first face that exists: the others are covered
+                for &(offset, af) in group {
+                    let adj = (cube + offset.into(), af);
+                    if open_faces.contains(&adj) {
+                        if !expanded.contains(&adj) {
+                            frontier.push_back(adj);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+
+        let external_faces_count = expanded.len();
+        external_faces_count.into()
+    }
+}
+
+// a lumpy droplet about `size` cubes across, with some air pockets inside
+impl Generator for Day18 {
+    const SIZE: usize = 20;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2) as isize;
+        let center = size / 2;
+        let radius = (size / 2) as f64;
+        let mut res = String::new();
+        for x in 0..size {
+            for y in 0..size {
+                for z in 0..size {
+                    let d = [x, y, z]
+                        .iter()
+                        .map(|c| ((c - center) as f64).powi(2))
+                        .sum::<f64>()
+                        .sqrt();
+                    let inside = d < radius * (0.7 + 0.1 * rng.below(4) as f64);
+                    if d < 1.0 || inside && !rng.chance(0.05) {
+                        res.push_str(&format!("{},{},{}\n", x, y, z));
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day18::solve(SAMPLE), Ok((64.into(), 58.into())));
+    }
+
+    // faces touching the air that floods in from around the bounding box
+    fn naive_exterior(cubes: &[Cube]) -> usize {
+        let cubes: HashSet<Cube> = cubes.iter().copied().collect();
+        let lo = cubes.iter().map(|c| c.x.min(c.y).min(c.z)).min().unwrap() - 1;
+        let hi = cubes.iter().map(|c| c.x.max(c.y).max(c.z)).max().unwrap() + 1;
+        let inside = |c: Cube| [c.x, c.y, c.z].iter().all(|c| (lo..=hi).contains(c));
+
+        let mut faces = 0;
+        let mut air = HashSet::from([Cube::new(lo, lo, lo)]);
+        let mut queue = VecDeque::from([Cube::new(lo, lo, lo)]);
+        while let Some(pos) = queue.pop_front() {
+            for next in pos.neighbours6() {
+                if cubes.contains(&next) {
+                    faces += 1;
+                } else if inside(next) && air.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        faces
+    }
+
+    // the piece of a dense random box around its first cube, so that it is in
+    // one piece like the puzzle ones but has pockets of air
+    fn random_droplet(rng: &mut Rng, side: isize, density: f64) -> Vec<Cube> {
+        let mut box_cubes = HashSet::new();
+        for x in 0..side {
+            for y in 0..side {
+                for z in 0..side {
+                    if rng.chance(density) {
+                        box_cubes.insert(Cube::new(x, y, z));
+                    }
+                }
+            }
+        }
+        let Some(&first) = box_cubes.iter().min() else {
+            return vec![Cube::ORIGIN];
+        };
+        let mut cubes = vec![first];
+        box_cubes.remove(&first);
+        let mut i = 0;
+        while i < cubes.len() {
+            for next in cubes[i].neighbours6() {
+                if box_cubes.remove(&next) {
+                    cubes.push(next);
+                }
+            }
+            i += 1;
+        }
+        rng.shuffle(&mut cubes);
+        cubes
+    }
+
+    #[test]
+    fn surface_matches_naive() {
+        let mut rng = Rng::new(18);
+        for _ in 0..300 {
+            let side = rng.range(1..=6);
+            let density = 0.5 + rng.below(5) as f64 / 10.0;
+            let cubes = random_droplet(&mut rng, side, density);
+            let naive_total: usize = cubes
+                .iter()
+                .map(|c| c.neighbours6().filter(|next| !cubes.contains(next)).count())
+                .sum();
+            assert_eq!(Day18::part1(&cubes), naive_total.into(), "{:?}", cubes);
+            assert_eq!(
+                Day18::part2(&cubes),
+                naive_exterior(&cubes).into(),
+                "{:?}",
+                cubes
+            );
+        }
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(18).read().expect("cannot read input");
+        assert_eq!(Day18::solve(&input), Ok((4548.into(), 2588.into())));
+    }
+}
@@ -0,0 +1,419 @@
+use std::fmt::Display;
+
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    info,
+    parse::{self, Line},
+    search::{Search, SearchProblem, Strategy},
+    Answer, ParseError, Solution,
+};
+
+const NUM_RES: usize = 4;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+type Res = usize;
+
+type ResVec = [usize; NUM_RES];
+
+// cost of each robot, in each resource
+pub type Costs = [ResVec; NUM_RES];
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    pub id: usize,
+    pub costs: Costs,
+}
+
+#[derive(Debug, Clone)]
+struct SearchNode {
+    t: usize,
+    time_budget: usize,
+    robots: ResVec,
+    resources: ResVec,
+    upper_bound: usize,
+}
+
+impl SearchNode {
+    fn initial(time_budget: usize, costs: &Costs) -> Self {
+        Self {
+            t: 0,
+            time_budget,
+            robots: [1, 0, 0, 0],
+            resources: [0; NUM_RES],
+            upper_bound: compute_bound(time_budget, &[0; NUM_RES], &[1, 0, 0, 0], costs),
+        }
+    }
+
+    fn can_build(resources: &ResVec, cost: &ResVec) -> bool {
+        resources
+            .iter()
+            .zip(cost)
+            .map(|(avail, cost)| avail.checked_sub(*cost))
+            .all(|x| x.is_some())
+    }
+
+    fn step_resources(resources: &mut ResVec, robots: &ResVec) {
+        for i in 0..NUM_RES {
+            resources[i] += robots[i];
+        }
+    }
+
+    fn pay_cost(resources: &mut ResVec, cost: &ResVec) {
+        for i in 0..NUM_RES {
+            resources[i] -= cost[i];
+        }
+    }
+
+    // the geodes at the end, building nothing more
+    fn utility(&self) -> usize {
+        self.resources[GEODE] + self.robots[GEODE] * (self.time_budget - self.t)
+    }
+
+    fn expand(&self, costs: &Costs) -> Vec<SearchNode> {
+        let mut children = Vec::new();
+        if self.t == self.time_budget {
+            return children;
+        }
+
+        // action: wait until resources are available and build each type of robot
+        let mut child_t = self.t;
+        let mut cur_resources = self.resources;
+
+        let mut rob_built = [false; NUM_RES]; // types of robots already accounted for
+
+        loop {
+            child_t += 1;
+            // terminate
+            if child_t == self.time_budget {
+                if children.is_empty() {
+                    Self::step_resources(&mut cur_resources, &self.robots);
+                    let child = SearchNode {
+                        t: child_t,
+                        time_budget: self.time_budget,
+                        resources: cur_resources,
+                        robots: self.robots,
+                        upper_bound: compute_bound(
+                            self.time_budget - child_t,
+                            &cur_resources,
+                            &self.robots,
+                            costs,
+                        ),
+                    };
+                    children.push(child);
+                }
+                break;
+            }
+
+            // try to build a robot
+            for (rob, cost) in costs.iter().enumerate() {
+                // action: build robot
+                if rob_built[rob] || !Self::can_build(&cur_resources, cost) {
+                    continue;
+                };
+                let mut child_resources = cur_resources;
+                Self::pay_cost(&mut child_resources, cost);
+                Self::step_resources(&mut child_resources, &self.robots);
+                let mut child_robots = self.robots;
+                child_robots[rob] += 1;
+
+                let child = SearchNode {
+                    t: child_t,
+                    time_budget: self.time_budget,
+                    resources: child_resources,
+                    robots: child_robots,
+                    upper_bound: compute_bound(
+                        self.time_budget - child_t,
+                        &child_resources,
+                        &child_robots,
+                        costs,
+                    ),
+                };
+                rob_built[rob] = true;
+                children.push(child);
+            }
+
+            // do nothing
+            if rob_built != [true; NUM_RES] {
+                Self::step_resources(&mut cur_resources, &self.robots); // do nothing for this step
+            } else {
+                break;
+            }
+        }
+
+        children
+    }
+}
+
+impl Display for SearchNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SearchNode(t: {}, res: {:?}, rob: {:?}, bound: {})",
+            self.t, self.resources, self.robots, self.upper_bound
+        )
+    }
+}
+
+fn compute_bound(
+    remaining_time: usize,
+    resources: &ResVec,
+    robots: &ResVec,
+    costs: &Costs,
+) -> usize {
+    let dt = remaining_time;
+
+    let spec_ore = resources[ORE] + dt * robots[ORE];
+    let spec_ore = spec_ore + (spec_ore / costs[ORE][ORE]).min(dt) / 2;
+    let spec_clay =
+        resources[CLAY] + dt * robots[CLAY] + dt * (spec_ore / costs[CLAY][ORE]).min(dt) / 2;
+    let spec_obs = resources[OBSIDIAN]
+        + dt * robots[OBSIDIAN]
+        + dt * usize::min(
+            spec_ore / costs[OBSIDIAN][ORE],
+            spec_clay / costs[OBSIDIAN][CLAY],
+        )
+        .min(dt)
+            / 2;
+    let spec_geode = resources[GEODE]
+        + dt * robots[GEODE]
+        + dt * usize::min(
+            spec_ore / costs[GEODE][ORE],
+            spec_obs / costs[GEODE][OBSIDIAN],
+        )
+        .min(dt)
+            / 2;
+
+    #[allow(clippy::let_and_return)]
+    spec_geode
+}
+
+fn parse_res(line: &Line, s: &str) -> Result<Res, ParseError> {
+    match s {
+        "ore" => Ok(ORE),
+        "clay" => Ok(CLAY),
+        "obsidian" => Ok(OBSIDIAN),
+        "geode" => Ok(GEODE),
+        _ => Err(line.error(s, "unknown resource")),
+    }
+}
+
+const REQUIRED_COSTS: [(Res, Res, &str); 6] = [
+    (ORE, ORE, "ore cost of the ore robot"),
+    (CLAY, ORE, "ore cost of the clay robot"),
+    (OBSIDIAN, ORE, "ore cost of the obsidian robot"),
+    (OBSIDIAN, CLAY, "clay cost of the obsidian robot"),
+    (GEODE, ORE, "ore cost of the geode robot"),
+    (GEODE, OBSIDIAN, "obsidian cost of the geode robot"),
+];
+
+pub fn parse_blueprint(text: &str) -> Result<Blueprint, ParseError> {
+    let line = Line::detached(text);
+    let mut costs = Costs::default();
+    let Some((head, s)) = text.split_once(": ") else {
+        return Err(line.missing("':'"));
+    };
+    let id = line.scan_part(head, "Blueprint {id}")?;
+
+    for s in s.split(". ") {
+        let s = s.trim_end_matches('.');
+        let s = s.trim_start_matches("Each ");
+
+        let (rob, s) = s
+            .split_once(' ')
+            .ok_or_else(|| line.error(s, "expected a robot"))?;
+        let rob: Res = parse_res(&line, rob)?;
+
+        let s = s.trim_start_matches("robot costs ");
+        for s in s.split("and ") {
+            let (amount, s) = s
+                .split_once(' ')
+                .ok_or_else(|| line.error(s, "expected a cost"))?;
+            let n: usize = line.parse(amount)?;
+            if n == 0 {
+                return Err(line.error(amount, "zero cost"));
+            }
+
+            let res = s.trim_end();
+            let res: Res = parse_res(&line, res)?;
+
+            costs[rob][res] = n;
+        }
+    }
+    // the search divides by these
+    for (rob, res, what) in REQUIRED_COSTS {
+        if costs[rob][res] == 0 {
+            return Err(line.missing(what));
+        }
+    }
+    Ok(Blueprint { id, costs })
+}
+
+// the most geodes that can be opened in time with a blueprint
+struct Factory<'a> {
+    time_budget: usize,
+    costs: &'a Costs,
+}
+
+impl SearchProblem for Factory<'_> {
+    type Node = SearchNode;
+    type Key = (usize, ResVec, ResVec);
+
+    fn start(&self) -> SearchNode {
+        SearchNode::initial(self.time_budget, self.costs)
+    }
+
+    fn key(&self, node: &SearchNode) -> Self::Key {
+        (node.t, node.robots, node.resources)
+    }
+
+    fn expand(&self, node: &SearchNode) -> Vec<(SearchNode, usize)> {
+        node.expand(self.costs)
+            .into_iter()
+            .map(|child| (child, 1))
+            .collect()
+    }
+
+    fn value(&self, node: &SearchNode) -> usize {
+        node.utility()
+    }
+
+    fn bound(&self, node: &SearchNode) -> usize {
+        node.upper_bound
+    }
+}
+
+/// The most geodes that can be opened in `time_budget` minutes.
+pub fn solve_blueprint(time_budget: usize, costs: &Costs) -> usize {
+    let factory = Factory { time_budget, costs };
+    let outcome = Search::new(&factory).run(Strategy::BranchAndBound);
+    let best = outcome.found.expect("no initial node");
+    debug!("Max {} for {} ({})", best.score, best.node, outcome.stats);
+    best.score
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: usize = 19;
+
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|l| parse_blueprint(l.text).map_err(|e| l.locate(l.text, e)))
+            .collect()
+    }
+
+    fn part1(blueprints: &Self::Input) -> Answer {
+        info!("Part 1");
+        let ql_sum: usize = blueprints
+            .iter()
+            .map(|blueprint| {
+                info!("Blueprint {}", blueprint.id);
+
+                let sol = solve_blueprint(24, &blueprint.costs);
+                sol * blueprint.id
+            })
+            .sum();
+        ql_sum.into()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Answer {
+        info!("Part 2");
+        let sol_prod: usize = blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| {
+                info!("Blueprint {}", blueprint.id);
+                solve_blueprint(32, &blueprint.costs)
+            })
+            .product();
+        sol_prod.into()
+    }
+}
+
+// `size` blueprints, with costs in the same ranges as the real ones
+impl Generator for Day19 {
+    const SIZE: usize = 30;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|i| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    i,
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                    rng.range(2..=4),
+                    rng.range(5..=20)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day19::solve(SAMPLE), Ok((33.into(), 3472.into())));
+    }
+
+    #[test]
+    fn quality_levels() {
+        // the quality level comes from the id, not the position
+        let input = SAMPLE.lines().nth(1).unwrap();
+        assert_eq!(Day19::part1(&Day19::parse(input).unwrap()), 24.into());
+
+        let err = Day19::parse(&SAMPLE.replace("Blueprint 2", "Blueprint two")).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 11, "two"));
+    }
+
+    #[test]
+    fn malformed() {
+        let input = SAMPLE.replace("2 ore. Each obsidian", "2 gold. Each obsidian");
+        let err = Day19::parse(&input).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (19, 1, 66));
+        assert_eq!(err.text, "gold");
+
+        let err = parse_blueprint("Blueprint 1: Each ore robot costs x ore.").unwrap_err();
+        assert_eq!((err.line, err.column), (0, 35));
+    }
+
+    // found by fuzzing: the search divided by the missing costs
+    #[test]
+    fn missing_costs() {
+        let err = parse_blueprint("Blueprint 1: obsidian 0 geode").unwrap_err();
+        assert_eq!(
+            (err.column, err.text.as_str(), err.reason.as_str()),
+            (23, "0", "zero cost")
+        );
+
+        let line = SAMPLE.lines().next().unwrap();
+        let err = parse_blueprint(&line.replace("2 ore and 7 obsidian", "2 ore")).unwrap_err();
+        assert_eq!(err.reason, "missing obsidian cost of the geode robot");
+        let err = parse_blueprint(&line.replace("3 ore and 14 clay", "14 clay")).unwrap_err();
+        assert_eq!(err.reason, "missing ore cost of the obsidian robot");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(19).read().expect("cannot read input");
+        assert_eq!(Day19::solve(&input), Ok((1703.into(), 5301.into())));
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp::Ordering::{Equal, Greater, Less};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Ord for Choice {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self {
+            Self::Rock => match other {
+                Self::Rock => Equal,
+                Self::Paper => Less,
+                Self::Scissors => Greater,
+            },
+            Self::Paper => match other {
+                Self::Rock => Greater,
+                Self::Paper => Equal,
+                Self::Scissors => Less,
+            },
+            Self::Scissors => match other {
+                Self::Rock => Less,
+                Self::Paper => Greater,
+                Self::Scissors => Equal,
+            },
+        }
+    }
+}
+
+impl PartialOrd for Choice {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Choice {
+    fn score(&self) -> usize {
+        match self {
+            Choice::Rock => 1,
+            Choice::Paper => 2,
+            Choice::Scissors => 3,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Lose,
+    Tie,
+    Win,
+}
+
+impl Outcome {
+    fn score(&self) -> usize {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Tie => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+fn decide(opp: &Choice, outcome: &Outcome) -> Choice {
+    match opp {
+        Choice::Rock => match outcome {
+            Outcome::Lose => Choice::Scissors,
+            Outcome::Tie => Choice::Rock,
+            Outcome::Win => Choice::Paper,
+        },
+        Choice::Paper => match outcome {
+            Outcome::Lose => Choice::Rock,
+            Outcome::Tie => Choice::Paper,
+            Outcome::Win => Choice::Scissors,
+        },
+        Choice::Scissors => match outcome {
+            Outcome::Lose => Choice::Paper,
+            Outcome::Tie => Choice::Scissors,
+            Outcome::Win => Choice::Rock,
+        },
+    }
+}
+
+// second column of the strategy guide, whose meaning changes between parts
+#[derive(Debug, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl Column {
+    fn as_choice(&self) -> Choice {
+        match self {
+            Column::X => Choice::Rock,
+            Column::Y => Choice::Paper,
+            Column::Z => Choice::Scissors,
+        }
+    }
+
+    fn as_outcome(&self) -> Outcome {
+        match self {
+            Column::X => Outcome::Lose,
+            Column::Y => Outcome::Tie,
+            Column::Z => Outcome::Win,
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+
+    type Input = Vec<(Choice, Column)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|l| {
+                let (opp, col) = l
+                    .text
+                    .split_once(' ')
+                    .ok_or_else(|| l.missing("second column"))?;
+                let opp = match opp {
+                    "A" => Choice::Rock,
+                    "B" => Choice::Paper,
+                    "C" => Choice::Scissors,
+                    x => return Err(l.error(x, "invalid move")),
+                };
+                let col = match col {
+                    "X" => Column::X,
+                    "Y" => Column::Y,
+                    "Z" => Column::Z,
+                    x => return Err(l.error(x, "invalid move")),
+                };
+                Ok((opp, col))
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> Answer {
+        let total_score: usize = rounds
+            .iter()
+            .map(|(opp, col)| {
+                let mov = col.as_choice();
+                let outcome = match mov.cmp(opp) {
+                    Less => Outcome::Lose,
+                    Equal => Outcome::Tie,
+                    Greater => Outcome::Win,
+                };
+                mov.score() + outcome.score()
+            })
+            .sum();
+        total_score.into()
+    }
+
+    fn part2(rounds: &Self::Input) -> Answer {
+        let total_score: usize = rounds
+            .iter()
+            .map(|(opp, col)| {
+                let outcome = col.as_outcome();
+                decide(opp, &outcome).score() + outcome.score()
+            })
+            .sum();
+        total_score.into()
+    }
+}
+
+// `size` rounds
+impl Generator for Day2 {
+    const SIZE: usize = 2500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&["A", "B", "C"]),
+                    rng.choose(&["X", "Y", "Z"])
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day2::solve(SAMPLE), Ok((15.into(), 12.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(2).read().expect("cannot read input");
+        assert_eq!(Day2::solve(&input), Ok((13446.into(), 13509.into())));
+    }
+}
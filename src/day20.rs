@@ -1,9 +1,8 @@
-use std::{
-    cell::RefCell,
-    fmt::Display,
-    fs::File,
-    io::{BufRead, BufReader},
-    rc::Rc,
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, trace, Answer, ParseError, Solution,
 };
 
 type NodePtr<T> = Rc<RefCell<Node<T>>>;
@@ -93,6 +92,10 @@ impl CircleList<isize> {
     }
 
     fn move_node(&self, node_ptr: &NodePtr<isize>) {
+        // a lone number has nowhere to go
+        if self.len() < 2 {
+            return;
+        }
         let mut node = (*node_ptr).borrow_mut();
         let amt = node.value % (self.len() - 1) as isize;
         if amt == 0 {
@@ -190,56 +193,141 @@ impl<T: Copy> Iterator for CircleListIter<T> {
 
 const DECRYPTION_KEY: isize = 811589153;
 
-fn main() {
-    // part 1
-    let f = File::open("input/day20.txt").unwrap();
-    let read = BufReader::new(f);
-    let lines = read.lines();
-
-    let elems = lines.map(|l| {
-        let n: isize = l.unwrap().parse().unwrap();
-        n
-    });
-
-    // parse
-    let list: CircleList<isize> = elems.collect();
-
-    // mix once
-    list.mix();
-
+// sum of the 1000th, 2000th and 3000th numbers after zero
+fn grove_coordinates(list: &CircleList<isize>) -> isize {
     let mut it = list.iter().skip(1);
     let mut acc: isize = 0;
     for _ in 0..3 {
         let value = it.nth((1000 - 1) % list.len()).unwrap();
-        // println!("{}", value);
+        trace!("{}", value);
         acc += value;
     }
-    println!("{}", acc);
+    acc
+}
+
+pub struct Day20;
 
-    // part 2
-    let f = File::open("input/day20.txt").unwrap();
-    let read = BufReader::new(f);
-    let lines = read.lines();
+impl Solution for Day20 {
+    const DAY: usize = 20;
 
-    let elems = lines.map(|l| {
-        let n: isize = l.unwrap().parse().unwrap();
-        n * DECRYPTION_KEY
-    });
+    // encrypted file
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|l| l.parse::<isize>(l.text))
+            .collect()
+    }
 
-    // parse
-    let list: CircleList<isize> = elems.collect();
+    fn part1(elems: &Self::Input) -> Answer {
+        let list: CircleList<isize> = elems.iter().copied().collect();
 
-    // mix ten times
-    for _ in 0..10 {
+        // mix once
         list.mix();
+
+        grove_coordinates(&list).into()
     }
 
-    let mut it = list.iter().skip(1);
-    let mut acc: isize = 0;
-    for _ in 0..3 {
-        let value = it.nth((1000 - 1) % list.len()).unwrap();
-        // println!("{}", value);
-        acc += value;
+    fn part2(elems: &Self::Input) -> Answer {
+        let list: CircleList<isize> = elems.iter().map(|n| n * DECRYPTION_KEY).collect();
+
+        // mix ten times
+        for _ in 0..10 {
+            list.mix();
+        }
+
+        grove_coordinates(&list).into()
+    }
+}
+
+// `size` numbers, only one of them zero
+impl Generator for Day20 {
+    const SIZE: usize = 5000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers: Vec<isize> = (0..size.max(1))
+            .map(|_| match rng.range(-10000..=9999) {
+                n if n >= 0 => n + 1,
+                n => n,
+            })
+            .collect();
+        let zero = rng.below(numbers.len());
+        numbers[zero] = 0;
+        numbers.iter().map(|n| format!("{}\n", n)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day20::solve(SAMPLE), Ok((3.into(), 1623178306.into())));
+    }
+
+    // moves the numbers around a plain vector, returns them starting from zero
+    fn naive_mix(numbers: &[isize], rounds: usize) -> Vec<isize> {
+        let mut order: Vec<usize> = (0..numbers.len()).collect();
+        for _ in 0..rounds {
+            for (i, n) in numbers.iter().enumerate() {
+                let pos = order.iter().position(|&j| j == i).unwrap();
+                order.remove(pos);
+                let dest = (pos as isize + n).rem_euclid(numbers.len().max(2) as isize - 1);
+                order.insert(dest as usize, i);
+            }
+        }
+        let zero = order.iter().position(|&i| numbers[i] == 0).unwrap();
+        order.rotate_left(zero);
+        order.iter().map(|&i| numbers[i]).collect()
+    }
+
+    #[test]
+    fn mix_matches_naive() {
+        let mut rng = Rng::new(20);
+        for _ in 0..500 {
+            let len = rng.below(12) + 1;
+            let mut numbers: Vec<isize> = (0..len)
+                .map(|_| rng.range(-30..=30))
+                .filter(|&n| n != 0)
+                .collect();
+            numbers.insert(rng.below(numbers.len() + 1), 0);
+            if rng.chance(0.3) {
+                numbers.iter_mut().for_each(|n| *n *= DECRYPTION_KEY);
+            }
+            let rounds = rng.below(3) + 1;
+
+            let list: CircleList<isize> = numbers.iter().copied().collect();
+            for _ in 0..rounds {
+                list.mix();
+            }
+            let mixed: Vec<isize> = list.iter().take(numbers.len()).collect();
+            assert_eq!(
+                mixed,
+                naive_mix(&numbers, rounds),
+                "{:?} mixed {} times",
+                numbers,
+                rounds
+            );
+        }
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(20).read().expect("cannot read input");
+        assert_eq!(
+            Day20::solve(&input),
+            Ok((5962.into(), 9862431387256_isize.into()))
+        );
     }
-    println!("{}", acc);
 }
@@ -0,0 +1,460 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    str::{self, FromStr},
+};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+#[derive(Clone)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "*" => Ok(Self::Mul),
+            "/" => Ok(Self::Div),
+            _ => Err(ParseError::new(0, s, "unknown operation")),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    x: u32,
+    y: u32,
+    op: Op,
+}
+
+impl Monkey {
+    fn try_compute(&self, solved: &HashMap<u32, isize>) -> Option<isize> {
+        let x = solved.get(&self.x)?;
+        let y = solved.get(&self.y)?;
+        match self.op {
+            Op::Add => Some(x + y),
+            Op::Sub => Some(x - y),
+            Op::Div => Some(x / y),
+            Op::Mul => Some(x * y),
+        }
+    }
+
+    fn solve_unknown(&self, res: isize, solved: &HashMap<u32, isize>) -> Option<isize> {
+        if let Some(x) = solved.get(&self.x) {
+            match self.op {
+                Op::Add => Some(res - x),
+                Op::Sub => Some(x - res),
+                Op::Div => Some(x / res),
+                Op::Mul => Some(res / x),
+            }
+        } else if let Some(y) = solved.get(&self.y) {
+            match self.op {
+                Op::Add => Some(res - y),
+                Op::Sub => Some(res + y),
+                Op::Div => Some(res * y),
+                Op::Mul => Some(res / y),
+            }
+        } else {
+            None
+        }
+    }
+
+    fn get_known(&self, solved: &HashMap<u32, isize>) -> Option<isize> {
+        if let Some(x) = solved.get(&self.x) {
+            Some(*x)
+        } else {
+            solved.get(&self.y).copied()
+        }
+    }
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self.op {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '*',
+            Op::Div => '/',
+        };
+        write!(f, "{} {} {}", decode(&self.x), op, decode(&self.y))
+    }
+}
+
+// monkey names are four lowercase letters
+fn parse_name(line: &parse::Line, s: &str) -> Result<u32, ParseError> {
+    if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_lowercase()) {
+        return Err(line.error(s, "invalid monkey name"));
+    }
+    Ok(encode(s))
+}
+
+fn encode(s: &str) -> u32 {
+    let mut res: u32 = 0;
+    for (i, b) in s.bytes().rev().enumerate() {
+        res += (b as u32) << (8 * i);
+    }
+    res
+}
+
+#[allow(unused)]
+fn decode(n: &u32) -> String {
+    let mut s: [u8; 4] = [0; 4];
+    s[0] = ((n >> 24) & 0xff) as u8;
+    s[1] = ((n >> 16) & 0xff) as u8;
+    s[2] = ((n >> 8) & 0xff) as u8;
+    s[3] = (n & 0xff) as u8;
+    let s = str::from_utf8(&s).unwrap();
+    s.to_string()
+}
+
+fn solve_cascade(
+    start: u32,
+    solved: &mut HashMap<u32, isize>,
+    unsolved: &mut HashMap<u32, Monkey>,
+    waiting_on: &mut HashMap<u32, Vec<u32>>,
+) {
+    let mut queue: VecDeque<u32> = VecDeque::new();
+
+    if let Some(ks) = waiting_on.remove(&start) {
+        for k in ks {
+            queue.push_back(k);
+        }
+    }
+
+    while let Some(k) = queue.pop_front() {
+        // try to solve this
+        let m = unsolved.get(&k).expect("expected unsolved");
+        if let Some(res) = m.try_compute(solved) {
+            // solved
+            unsolved.remove(&k);
+            solved.insert(k, res);
+            // enqueue its dependants
+            if let Some(ks) = waiting_on.remove(&k) {
+                for k in ks {
+                    queue.push_back(k);
+                }
+            }
+        }
+    }
+}
+
+fn solve_root_eq(
+    solved: &mut HashMap<u32, isize>,
+    unsolved: &mut HashMap<u32, Monkey>,
+    waiting_on: &mut HashMap<u32, Vec<u32>>,
+) -> isize {
+    let mut path_from_root: VecDeque<u32> = VecDeque::new();
+
+    let mut cur = encode("humn");
+    while let Some(ks) = waiting_on.get(&cur) {
+        match ks[..] {
+            [k] => {
+                path_from_root.push_front(k);
+                cur = k;
+            }
+            _ => panic!(),
+        }
+    }
+
+    // find the unknown value in the operation
+    let root = path_from_root.pop_front().unwrap();
+    let root = unsolved.get(&root).unwrap();
+    let mut res = root.get_known(solved).unwrap();
+    while let Some(k) = path_from_root.pop_front() {
+        let m = unsolved.get(&k).unwrap();
+        res = m.solve_unknown(res, solved).unwrap();
+    }
+    res
+}
+
+pub enum Job {
+    Number(isize),
+    Operation(Monkey),
+}
+
+struct Solver {
+    // known/computed numbers
+    solved: HashMap<u32, isize>,
+    unsolved: HashMap<u32, Monkey>,
+    // all monkeys waiting on a given monkey
+    waiting_on: HashMap<u32, Vec<u32>>,
+}
+
+impl Solver {
+    // solve as many monkeys as possible, optionally ignoring what the human yells
+    fn new(jobs: &[(u32, Job)], skip_humn: bool) -> Self {
+        let mut solved = HashMap::<u32, isize>::new();
+        let mut unsolved = HashMap::<u32, Monkey>::new();
+        let mut waiting_on = HashMap::<u32, Vec<u32>>::new();
+
+        for (k, job) in jobs {
+            let k = *k;
+            if skip_humn && k == encode("humn") {
+                continue;
+            }
+
+            match job {
+                Job::Number(v) => {
+                    solved.insert(k, *v);
+                    solve_cascade(k, &mut solved, &mut unsolved, &mut waiting_on);
+                }
+                Job::Operation(m) => {
+                    let m = m.clone();
+                    let (x, y) = (m.x, m.y);
+
+                    if let Some(v) = m.try_compute(&solved) {
+                        solved.insert(k, v);
+                        solve_cascade(k, &mut solved, &mut unsolved, &mut waiting_on);
+                    } else {
+                        unsolved.insert(k, m);
+                        if !solved.contains_key(&x) {
+                            waiting_on.entry(x).or_default().push(k);
+                        }
+                        if !solved.contains_key(&y) {
+                            waiting_on.entry(y).or_default().push(k);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            solved,
+            unsolved,
+            waiting_on,
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: usize = 21;
+
+    type Input = Vec<(u32, Job)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| {
+                // parse
+                let (k, s) = line
+                    .text
+                    .split_once(": ")
+                    .ok_or_else(|| line.missing("': '"))?;
+                let k = parse_name(&line, k)?;
+
+                let toks: Vec<&str> = s.split(' ').collect();
+
+                let job = match toks[..] {
+                    [v] => {
+                        let v = line.parse(v)?;
+                        Job::Number(v)
+                    }
+                    [x, op, y] => {
+                        let x: u32 = parse_name(&line, x)?;
+                        let y: u32 = parse_name(&line, y)?;
+                        let op: Op = op.parse().map_err(|e| line.locate(op, e))?;
+
+                        Job::Operation(Monkey { x, y, op })
+                    }
+                    _ => return Err(line.error(s, "expected a number or an operation")),
+                };
+                Ok((k, job))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|jobs| {
+                for name in ["root", "humn"] {
+                    if !jobs.iter().any(|(k, _)| *k == encode(name)) {
+                        return Err(ParseError::input(
+                            Self::DAY,
+                            format!("missing monkey {}", name),
+                        ));
+                    }
+                }
+                Ok(jobs)
+            })
+    }
+
+    fn part1(jobs: &Self::Input) -> Answer {
+        let solver = Solver::new(jobs, false);
+        let root = solver.solved.get(&encode("root")).expect("root not solved");
+        (*root).into()
+    }
+
+    fn part2(jobs: &Self::Input) -> Answer {
+        let mut solver = Solver::new(jobs, true);
+        let humn_value = solve_root_eq(
+            &mut solver.solved,
+            &mut solver.unsolved,
+            &mut solver.waiting_on,
+        );
+        humn_value.into()
+    }
+}
+
+// random monkeys for the generator, each yelling a known number
+struct Troop<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = self.rng.word(4);
+            if self.names.insert(name.clone()) {
+                break name;
+            }
+        }
+    }
+
+    // about `size` monkeys, the first of which yells `value`, divisions are
+    // always exact
+    fn yell(&mut self, value: isize, size: usize) -> String {
+        let name = self.name();
+        if size < 3 {
+            self.jobs.push(format!("{}: {}", name, value));
+            return name;
+        }
+        let divisor = (2..=9).rev().find(|d| value % d == 0);
+        let (x, op, y) = match self.rng.below(4) {
+            0 if value >= 2 => {
+                let x = self.rng.range(1..=value - 1);
+                (x, '+', value - x)
+            }
+            1 if divisor.is_some() => (value / divisor.unwrap(), '*', divisor.unwrap()),
+            2 if value < 1 << 30 => {
+                let y = self.rng.range(2..=5);
+                (value * y, '/', y)
+            }
+            _ => {
+                let y = self.rng.range(1..=20);
+                (value + y, '-', y)
+            }
+        };
+        let left = self.rng.below(size - 2) + 1;
+        let x = self.yell(x, left);
+        let y = self.yell(y, size - 1 - left);
+        self.jobs.push(format!("{}: {} {} {}", name, x, op, y));
+        name
+    }
+}
+
+// about `size` monkeys, the human being a few operations below the root
+impl Generator for Day21 {
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let depth = (size / 20).clamp(1, 40);
+        let budget = size.max(4) / (2 * depth);
+        let humn = rng.range(1..=1000000);
+        let mut troop = Troop {
+            rng,
+            names: ["root", "humn"].map(String::from).into(),
+            jobs: Vec::new(),
+        };
+        troop
+            .jobs
+            .push(format!("humn: {}", troop.rng.range(1..=5000)));
+
+        // the value yelled by each monkey between the human and the root,
+        // when the human yells the right number
+        let (mut value, mut below) = (humn, "humn".to_string());
+        for _ in 0..depth {
+            let name = troop.name();
+            let (other, op, other_first) = match troop.rng.below(4) {
+                0 if value >= 2 => (troop.rng.range(1..=value - 1), '-', false),
+                1 if value < 1 << 30 => (troop.rng.range(2..=5), '*', troop.rng.chance(0.5)),
+                2 => (value + troop.rng.range(1..=1000), '-', true),
+                3 if value % 2 == 0 => (2, '/', false),
+                _ => (troop.rng.range(1..=1000), '+', troop.rng.chance(0.5)),
+            };
+            value = match (op, other_first) {
+                ('+', _) => value + other,
+                ('*', _) => value * other,
+                ('/', _) => value / other,
+                ('-', true) => other - value,
+                _ => value - other,
+            };
+            let other = troop.yell(other, budget);
+            let job = if other_first {
+                format!("{}: {} {} {}", name, other, op, below)
+            } else {
+                format!("{}: {} {} {}", name, below, op, other)
+            };
+            troop.jobs.push(job);
+            below = name;
+        }
+        let other = troop.yell(value, size.saturating_sub(troop.jobs.len() + 1));
+        let op = troop.rng.choose(&['+', '-']);
+        troop.jobs.push(format!("root: {} {} {}", below, op, other));
+
+        let mut jobs = troop.jobs;
+        rng.shuffle(&mut jobs);
+        jobs.iter().map(|job| format!("{}\n", job)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day21::solve(SAMPLE), Ok((152.into(), 301.into())));
+    }
+
+    // short and malformed lines used to panic
+    #[test]
+    fn malformed() {
+        for input in [
+            "roo: 5",
+            "root: 5\nhumn",
+            "root: pppw ^ sjmn\nhumn: 5",
+            "root: 1\nhumn: ü",
+        ] {
+            assert!(Day21::parse(input).is_err(), "{}", input);
+        }
+        let err = Day21::parse("root: pppw + sj\nhumn: 5").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(err.text, "sj");
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(21).read().expect("cannot read input");
+        assert_eq!(
+            Day21::solve(&input),
+            Ok((51928383302238_isize.into(), 3305669217840_isize.into()))
+        );
+    }
+}
@@ -0,0 +1,527 @@
+use std::ops::{Index, Range};
+
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    geometry::Direction,
+    grid::Grid,
+    parse::{self, Line},
+    render::{Glyph, Render, Tile, Tone},
+    trace, Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Elem {
+    #[default]
+    Empty,
+    Open,
+    Wall,
+}
+
+const OPEN: Glyph = Glyph::new('.', Tone::Faint);
+const WALL: Glyph = Glyph::new('#', Tone::Solid);
+
+impl Tile for Elem {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Elem::Empty => Glyph::new(' ', Tone::Plain),
+            Elem::Open => OPEN,
+            Elem::Wall => WALL,
+        }
+    }
+
+    fn legend() -> &'static [(Glyph, &'static str)] {
+        &[(OPEN, "open tile"), (WALL, "wall")]
+    }
+}
+
+type Vec3 = [isize; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn dot(u: Vec3, v: Vec3) -> isize {
+    u.iter().zip(v).map(|(a, b)| a * b).sum()
+}
+
+// a face of the folded cube: its tile in the map, its outward normal
+// and where its local axes (going east and south) point to
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    tile: (usize, usize),
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    fn heading(&self, dir: Direction) -> Vec3 {
+        match dir {
+            Direction::E => self.right,
+            Direction::S => self.down,
+            Direction::W => neg(self.right),
+            Direction::N => neg(self.down),
+        }
+    }
+
+    // the face we get to by folding along the edge in direction dir
+    fn fold(&self, dir: Direction, tile: (usize, usize)) -> Self {
+        let normal = self.heading(dir);
+        let (right, down) = match dir {
+            Direction::E => (neg(self.normal), self.down),
+            Direction::W => (self.normal, self.down),
+            Direction::S => (self.right, neg(self.normal)),
+            Direction::N => (self.right, self.normal),
+        };
+        Self {
+            tile,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    cells: Grid<Elem>,
+    range_row: Vec<Range<usize>>,
+    range_col: Vec<Range<usize>>,
+    cube_size: usize,
+    faces: Vec<Face>,
+}
+
+// first and last non-empty cells of a row or column
+fn span(cells: &[Elem]) -> Range<usize> {
+    let start = cells
+        .iter()
+        .position(|&e| e != Elem::Empty)
+        .unwrap_or(cells.len());
+    let end = cells[start..]
+        .iter()
+        .position(|&e| e == Elem::Empty)
+        .map_or(cells.len(), |len| start + len);
+    Range { start, end }
+}
+
+// folds the net into a cube, one face per tile of size x size cells
+fn fold_cube(cells: &Grid<Elem>, size: usize) -> Option<Vec<Face>> {
+    let tiles = Grid::from_fn(cells.width() / size, cells.height() / size, |(ti, tj)| {
+        cells[(ti * size, tj * size)] != Elem::Empty
+    });
+    // the net must be made of whole tiles
+    if cells
+        .positions()
+        .any(|(i, j)| (cells[(i, j)] != Elem::Empty) != tiles[(i / size, j / size)])
+    {
+        return None;
+    }
+
+    let first = tiles.positions().find(|&tile| tiles[tile])?;
+    let mut faces = vec![Face {
+        tile: first,
+        normal: [0, 0, 1],
+        right: [1, 0, 0],
+        down: [0, 1, 0],
+    }];
+    let mut k = 0;
+    while k < faces.len() {
+        let face = faces[k];
+        for dir in Direction::ALL {
+            let Some(tile) = tiles.offset(face.tile, dir.offset()) else {
+                continue;
+            };
+            if tiles[tile] && faces.iter().all(|f| f.tile != tile) {
+                faces.push(face.fold(dir, tile));
+            }
+        }
+        k += 1;
+    }
+
+    let distinct = faces
+        .iter()
+        .enumerate()
+        .all(|(k, f)| faces[..k].iter().all(|g| g.normal != f.normal));
+    (faces.len() == 6 && distinct).then_some(faces)
+}
+
+impl Index<(usize, usize)> for Map {
+    type Output = Elem;
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        &self.cells[pos]
+    }
+}
+
+impl Map {
+    fn parse<'a, I: Iterator<Item = Line<'a>>>(lines: &mut I) -> Result<Self, ParseError> {
+        let rows: Vec<Line> = lines
+            .by_ref()
+            .take_while(|line| !line.text.is_empty())
+            .collect();
+        let width = rows.iter().map(|line| line.text.len()).max().unwrap_or(0);
+
+        // shorter rows are padded with empty cells
+        let mut cells = Grid::new(width, rows.len(), Elem::Empty);
+        for (i, line) in rows.iter().enumerate() {
+            for (j, c) in line.text.char_indices() {
+                match c {
+                    ' ' => (),
+                    '.' => cells[(i, j)] = Elem::Open,
+                    '#' => cells[(i, j)] = Elem::Wall,
+                    _ => return Err(line.error(&line.text[j..j + c.len_utf8()], "unexpected cell")),
+                };
+            }
+        }
+
+        // six square faces
+        let area = cells.iter().filter(|&&e| e != Elem::Empty).count();
+        let cube_size = ((area / 6) as f64).sqrt().round() as usize;
+        let faces = (cube_size > 0 && 6 * cube_size * cube_size == area)
+            .then(|| fold_cube(&cells, cube_size))
+            .flatten()
+            .ok_or_else(|| ParseError::input(Day22::DAY, "the map does not fold into a cube"))?;
+
+        let range_row = cells.rows().map(span).collect();
+        let range_col = (0..cells.width())
+            .map(|j| span(&cells.col(j).copied().collect::<Vec<_>>()))
+            .collect();
+        Ok(Map {
+            cells,
+            range_row,
+            range_col,
+            cube_size,
+            faces,
+        })
+    }
+
+    fn walk_plane(&self, pos: (usize, usize), dir: Direction, amt: usize) -> (usize, usize) {
+        let (i, j) = pos;
+        match dir {
+            Direction::E => {
+                let rng = &self.range_row[i];
+                let mut j_rel = j - rng.start;
+                for _ in 0..amt {
+                    let j_next =
+                        rng.start + (j_rel as isize + 1).rem_euclid(rng.len() as isize) as usize;
+                    if self[(i, j_next)] != Elem::Wall {
+                        j_rel = j_next - rng.start;
+                    } else {
+                        break;
+                    }
+                }
+                let j = rng.start + j_rel % rng.len();
+                (i, j)
+            }
+            Direction::W => {
+                let rng = &self.range_row[i];
+                let mut j_rel = j - rng.start;
+                for _ in 0..amt {
+                    let j_next =
+                        rng.start + (j_rel as isize - 1).rem_euclid(rng.len() as isize) as usize;
+                    if self[(i, j_next)] != Elem::Wall {
+                        j_rel = j_next - rng.start;
+                    } else {
+                        break;
+                    }
+                }
+                let j = rng.start + j_rel % rng.len();
+                (i, j)
+            }
+            Direction::S => {
+                let rng = &self.range_col[j];
+                let mut i_rel = i - rng.start;
+                for _ in 0..amt {
+                    let i_next =
+                        rng.start + (i_rel as isize + 1).rem_euclid(rng.len() as isize) as usize;
+                    if self[(i_next, j)] != Elem::Wall {
+                        i_rel = i_next - rng.start;
+                    } else {
+                        break;
+                    }
+                }
+                let i = rng.start + i_rel % rng.len();
+                (i, j)
+            }
+            Direction::N => {
+                let rng = &self.range_col[j];
+                let mut i_rel = i - rng.start;
+                for _ in 0..amt {
+                    let i_next =
+                        rng.start + (i_rel as isize - 1).rem_euclid(rng.len() as isize) as usize;
+                    if self[(i_next, j)] != Elem::Wall {
+                        i_rel = i_next - rng.start;
+                    } else {
+                        break;
+                    }
+                }
+                let i = rng.start + i_rel % rng.len();
+                (i, j)
+            }
+        }
+    }
+
+    fn walk_cube(
+        &self,
+        pos: (usize, usize),
+        dir: Direction,
+        amt: usize,
+    ) -> ((usize, usize), Direction) {
+        let (mut pos, mut dir) = (pos, dir);
+        for _ in 0..amt {
+            let (pos_next, dir_next) = self.step_cube(pos, dir);
+            if self[pos_next] == Elem::Wall {
+                break;
+            }
+            (pos, dir) = (pos_next, dir_next);
+        }
+        (pos, dir)
+    }
+
+    fn step_cube(&self, pos: (usize, usize), dir: Direction) -> ((usize, usize), Direction) {
+        if let Some(next) = self
+            .cells
+            .offset(pos, dir.offset())
+            .filter(|&next| self[next] != Elem::Empty)
+        {
+            return (next, dir);
+        }
+
+        // go over the edge, onto the face we are heading to
+        let size = self.cube_size;
+        let face = self.face_at(pos);
+        let heading = face.heading(dir);
+        let next = self
+            .faces
+            .iter()
+            .find(|f| f.normal == heading)
+            .expect("missing face");
+
+        // doubled 3d coordinates of the cell centres, the cube spans -size..=size
+        let s = size as isize;
+        let a = 2 * (pos.0 % size) as isize + 1 - s;
+        let b = 2 * (pos.1 % size) as isize + 1 - s;
+        let mut p: Vec3 = [0; 3];
+        for k in 0..3 {
+            p[k] = (s - 1) * face.normal[k] + a * face.down[k] + b * face.right[k] + heading[k];
+        }
+        let i = ((dot(p, next.down) + s - 1) / 2) as usize;
+        let j = ((dot(p, next.right) + s - 1) / 2) as usize;
+        let dir_next = Direction::ALL
+            .into_iter()
+            .find(|&d| next.heading(d) == neg(face.normal))
+            .expect("faces are not adjacent");
+        ((next.tile.0 * size + i, next.tile.1 * size + j), dir_next)
+    }
+
+    fn face_at(&self, (i, j): (usize, usize)) -> &Face {
+        let tile = (i / self.cube_size, j / self.cube_size);
+        self.faces
+            .iter()
+            .find(|f| f.tile == tile)
+            .expect("position outside of the cube")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Step {
+    Walk(usize),
+    TurnRight,
+    TurnLeft,
+}
+
+fn final_password(pos: (usize, usize), dir: Direction) -> usize {
+    1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + dir as usize
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: usize = 22;
+
+    // board and path to follow
+    type Input = (Map, Vec<Step>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(Self::DAY, input);
+        let map = Map::parse(&mut lines)?;
+
+        debug!("map:\n{}", map.cells.render());
+
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::input(Self::DAY, "missing path"))?;
+        let mut it = line.text.char_indices().peekable();
+        let mut path = Vec::new();
+        loop {
+            // parse the number
+            let start = it.peek().map_or(line.text.len(), |&(i, _)| i);
+            let mut end = start;
+            while let Some((i, c)) = it.next_if(|(_, c)| c.is_ascii_digit()) {
+                end = i + c.len_utf8();
+            }
+            let amt: usize = line.parse(&line.text[start..end])?;
+            path.push(Step::Walk(amt));
+
+            // parse the change of direction
+            match it.next() {
+                Some((_, 'R')) => path.push(Step::TurnRight),
+                Some((_, 'L')) => path.push(Step::TurnLeft),
+                Some((i, c)) => {
+                    return Err(line.error(&line.text[i..i + c.len_utf8()], "invalid turn"))
+                }
+                None => break,
+            };
+        }
+        Ok((map, path))
+    }
+
+    fn part1((map, path): &Self::Input) -> Answer {
+        let mut pos = {
+            let i = 0;
+            let j = map.range_row[i].start;
+            (i, j)
+        };
+        let mut dir = Direction::E;
+        debug!("start at {:?} facing {:?}", pos, dir);
+
+        for step in path {
+            match *step {
+                Step::Walk(amt) => {
+                    trace!("walk {:?}, {:?}", amt, dir);
+                    pos = map.walk_plane(pos, dir, amt);
+                }
+                Step::TurnRight => dir = dir.rotate_clock(),
+                Step::TurnLeft => dir = dir.rotate_anticlock(),
+            }
+        }
+        debug!("end at {:?} facing {:?}", pos, dir);
+
+        final_password(pos, dir).into()
+    }
+
+    fn part2((map, path): &Self::Input) -> Answer {
+        let mut pos = {
+            let i = 0;
+            let j = map.range_row[i].start;
+            (i, j)
+        };
+        let mut dir = Direction::E;
+        debug!("start at {:?} facing {:?}", pos, dir);
+
+        for step in path {
+            match *step {
+                Step::Walk(amt) => {
+                    trace!("walk {:?}, {:?}", amt, dir);
+                    (pos, dir) = map.walk_cube(pos, dir, amt);
+                }
+                Step::TurnRight => dir = dir.rotate_clock(),
+                Step::TurnLeft => dir = dir.rotate_anticlock(),
+            }
+        }
+        debug!("end at {:?} facing {:?}", pos, dir);
+
+        final_password(pos, dir).into()
+    }
+}
+
+// a random net of a cube with sides of `size` tiles, and a path of `40 * size`
+// steps
+impl Generator for Day22 {
+    const SIZE: usize = 50;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+
+        // unfold the faces one at a time, any order gives a valid net
+        let mut faces = vec![Face {
+            tile: (5, 5),
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        while faces.len() < 6 {
+            let candidates: Vec<Face> = faces
+                .iter()
+                .flat_map(|face| {
+                    Direction::ALL.into_iter().map(|dir| {
+                        let (di, dj) = dir.offset();
+                        let tile = (
+                            face.tile.0.wrapping_add_signed(di),
+                            face.tile.1.wrapping_add_signed(dj),
+                        );
+                        face.fold(dir, tile)
+                    })
+                })
+                .filter(|new| {
+                    faces
+                        .iter()
+                        .all(|f| f.tile != new.tile && f.normal != new.normal)
+                })
+                .collect();
+            faces.push(*rng.choose(&candidates));
+        }
+        let top = faces.iter().map(|f| f.tile.0).min().unwrap();
+        let left = faces.iter().map(|f| f.tile.1).min().unwrap();
+        let height = faces.iter().map(|f| f.tile.0 - top + 1).max().unwrap();
+        let width = faces.iter().map(|f| f.tile.1 - left + 1).max().unwrap();
+
+        let mut res = String::new();
+        for i in 0..height * size {
+            let mut row = String::new();
+            for j in 0..width * size {
+                let tile = (top + i / size, left + j / size);
+                row.push(match faces.iter().any(|f| f.tile == tile) {
+                    false => ' ',
+                    // the start must be open
+                    true if i == 0 && !row.contains('.') => '.',
+                    true if rng.chance(0.1) => '#',
+                    true => '.',
+                });
+            }
+            res.push_str(row.trim_end());
+            res.push('\n');
+        }
+
+        res.push('\n');
+        res.push_str(&rng.range(1..=size as isize).to_string());
+        for _ in 1..40 * size {
+            res.push(*rng.choose(&['L', 'R']));
+            res.push_str(&rng.range(1..=size as isize).to_string());
+        }
+        res.push('\n');
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day22::solve(SAMPLE), Ok((6032.into(), 5031.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(22).read().expect("cannot read input");
+        assert_eq!(Day22::solve(&input), Ok((196134.into(), 146011.into())));
+    }
+}
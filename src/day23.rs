@@ -0,0 +1,258 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crate::{
+    debug,
+    frames::{self, Rgb},
+    gen::{Generator, Rng},
+    geometry::Point2,
+    grid::{self, Grid},
+    parse,
+    render::{Glyph, Render, Tone},
+    Answer, ParseError, Solution,
+};
+
+const ROUNDS: usize = 10;
+
+type Pos = Point2<i32>;
+
+fn parse_elves(input: &str) -> Result<HashSet<Pos>, ParseError> {
+    let mut elves: HashSet<Pos> = HashSet::new();
+
+    for (i, line) in parse::lines(Day23::DAY, input).enumerate() {
+        for (j, c) in line.text.char_indices() {
+            match c {
+                '#' => {
+                    elves.insert(Pos::new(i as i32, j as i32));
+                }
+                '.' => (),
+                _ => return Err(line.error(&line.text[j..j + c.len_utf8()], "unexpected char")),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+fn do_round(round: usize, elves: &mut HashSet<Pos>) -> bool {
+    const MOVES_TO_CHECK: [[Pos; 3]; 4] = [
+        // north
+        [Pos::new(-1, 0), Pos::new(-1, 1), Pos::new(-1, -1)],
+        // south
+        [Pos::new(1, 0), Pos::new(1, 1), Pos::new(1, -1)],
+        // west
+        [Pos::new(0, -1), Pos::new(1, -1), Pos::new(-1, -1)],
+        // east
+        [Pos::new(0, 1), Pos::new(1, 1), Pos::new(-1, 1)],
+    ];
+    let offset = round % MOVES_TO_CHECK.len();
+
+    // first half: propose
+    let mut proposed_dests: HashMap<Pos, Vec<Pos>> = HashMap::new(); // dest -> elves that want to go there
+    for elf in elves.iter() {
+        let mut has_occupied_neighbor = false;
+        let mut proposed_move: Option<Pos> = None;
+
+        for k in 0..MOVES_TO_CHECK.len() {
+            let moves_dir = &MOVES_TO_CHECK[(offset + k) % MOVES_TO_CHECK.len()];
+            let conflict = moves_dir.iter().any(|mov| {
+                let dest = *elf + *mov;
+                elves.contains(&dest)
+            });
+            if !conflict {
+                proposed_move.get_or_insert(*elf + moves_dir[0]);
+            } else {
+                has_occupied_neighbor = true;
+            }
+        }
+        if has_occupied_neighbor {
+            // try to propose the move
+            if let Some(dest) = proposed_move {
+                proposed_dests.entry(dest).or_default().push(*elf);
+            }
+        }
+    }
+
+    // second half: move
+    let mut changed = false;
+    for (dest, candidates) in proposed_dests {
+        if let [elf] = candidates[..] {
+            // single candidate
+            assert!(elves.remove(&elf), "removed nonexisting");
+            assert!(elves.insert(dest), "added existing");
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn calc_bounding_box(elves: &HashSet<Pos>) -> (Range<i32>, Range<i32>) {
+    let mut i_min = i32::MAX;
+    let mut i_max = i32::MIN;
+    let mut j_min = i32::MAX;
+    let mut j_max = i32::MIN;
+
+    for &Pos { i, j } in elves {
+        i_min = i_min.min(i);
+        i_max = i_max.max(i);
+        j_min = j_min.min(j);
+        j_max = j_max.max(j);
+    }
+    (i_min..(i_max + 1), j_min..(j_max + 1))
+}
+
+const ELF: Glyph = Glyph::new('#', Tone::Alive);
+
+// the bounding box of the elves
+struct Ground<'a> {
+    elves: &'a HashSet<Pos>,
+    is: Range<i32>,
+    js: Range<i32>,
+}
+
+impl<'a> Ground<'a> {
+    fn new(elves: &'a HashSet<Pos>) -> Self {
+        let (is, js) = calc_bounding_box(elves);
+        Self { elves, is, js }
+    }
+}
+
+impl Render for Ground<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.js.len(), self.is.len())
+    }
+
+    fn glyph(&self, (i, j): grid::Pos) -> Glyph {
+        if self.elves.contains(&Pos::new(
+            self.is.start + i as i32,
+            self.js.start + j as i32,
+        )) {
+            ELF
+        } else {
+            Glyph::new('.', Tone::Faint)
+        }
+    }
+
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        &[(ELF, "elf")]
+    }
+}
+
+// the initial bounding box, grown by half its size on each side so that the
+// elves have room to spread
+fn frame_window(elves: &HashSet<Pos>) -> (Range<i32>, Range<i32>) {
+    let (is, js) = calc_bounding_box(elves);
+    let margin = is.len().max(js.len()) as i32 / 2;
+    (
+        (is.start - margin)..(is.end + margin),
+        (js.start - margin)..(js.end + margin),
+    )
+}
+
+// elves inside the window, those outside of it are not drawn
+fn frame(elves: &HashSet<Pos>, (is, js): &(Range<i32>, Range<i32>)) -> Grid<Rgb> {
+    const GROUND: Rgb = [30, 60, 30];
+    const ELF: Rgb = [120, 230, 110];
+
+    Grid::from_fn(js.len(), is.len(), |(i, j)| {
+        let pos = Pos::new(is.start + i as i32, js.start + j as i32);
+        if elves.contains(&pos) {
+            ELF
+        } else {
+            GROUND
+        }
+    })
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: usize = 23;
+
+    // positions of the elves
+    type Input = HashSet<Pos>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_elves(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+        debug!("== Initial State ==\n{}", Ground::new(&elves).render());
+        let window = frame_window(&elves);
+        frames::capture(|| frame(&elves, &window));
+
+        for round in 0..ROUNDS {
+            do_round(round, &mut elves);
+            frames::capture(|| frame(&elves, &window));
+            if round < 5 || round == 9 {
+                debug!(
+                    "== End of Round {} ==\n{}",
+                    round + 1,
+                    Ground::new(&elves).render()
+                );
+            }
+        }
+
+        let bb = calc_bounding_box(&elves);
+        let free_tiles = bb.0.len() * bb.1.len() - elves.len();
+        free_tiles.into()
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+
+        let window = frame_window(&elves);
+        frames::capture(|| frame(&elves, &window));
+
+        let mut round = 0;
+        while do_round(round, &mut elves) {
+            frames::capture(|| frame(&elves, &window));
+            round += 1;
+        }
+        (round + 1).into()
+    }
+}
+
+// a square of `size` by `size` tiles, about half of them with an elf
+impl Generator for Day23 {
+    const SIZE: usize = 72;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let row: String = (0..size.max(1))
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day23::solve(SAMPLE), Ok((110.into(), 20.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(23).read().expect("cannot read input");
+        assert_eq!(Day23::solve(&input), Ok((3871.into(), 925.into())));
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    iter, mem,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    debug,
+    frames::{self, Rgb},
+    gen::{Generator, Rng},
+    geometry::{Direction, Point2},
+    grid::{self, Grid},
+    log::{self, Level},
+    render::{Glyph, Render, Tone},
+    search::{Search, SearchProblem, Strategy},
+    trace, Answer, ParseError, Solution,
+};
+
+type Pos = Point2<usize>;
+
+// blizzards wrap around within the walls of a (height, width) valley
+fn blow(dir: Direction, pos: Pos, (height, width): (usize, usize)) -> Pos {
+    let mut res = pos + dir;
+    // walls
+    if res.i == 0 {
+        res.i = height - 2;
+    } else if res.i == height - 1 {
+        res.i = 1;
+    }
+    if res.j == 0 {
+        res.j = width - 2;
+    } else if res.j == width - 1 {
+        res.j = 1;
+    }
+    res
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    start: Pos,
+    end: Pos,
+    cells: Grid<Vec<Direction>>,
+}
+
+impl Index<&Pos> for Map {
+    type Output = Vec<Direction>;
+    fn index(&self, index: &Pos) -> &Self::Output {
+        &self.cells[(*index).into()]
+    }
+}
+
+impl IndexMut<&Pos> for Map {
+    fn index_mut(&mut self, index: &Pos) -> &mut Self::Output {
+        &mut self.cells[(*index).into()]
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(Day24::DAY, input, |c| "#.^v<>".contains(c).then_some(c))?;
+        if chars.width() < 3 || chars.height() < 3 {
+            return Err(ParseError::input(Day24::DAY, "the valley is too small"));
+        }
+        // the openings in the top and bottom walls
+        let opening = |i: usize| {
+            chars
+                .row(i)
+                .iter()
+                .position(|&c| c == '.')
+                .map(|j| Pos::new(i, j))
+                .ok_or_else(|| {
+                    ParseError::input(Day24::DAY, format!("no opening in row {}", i + 1))
+                })
+        };
+        let start = opening(0)?;
+        let end = opening(chars.height() - 1)?;
+        let cells = chars.map(|c| match c {
+            '^' => vec![Direction::N],
+            'v' => vec![Direction::S],
+            '<' => vec![Direction::W],
+            '>' => vec![Direction::E],
+            _ => vec![],
+        });
+        Ok(Map { start, end, cells })
+    }
+}
+
+impl Map {
+    fn step(&self) -> Self {
+        let mut map_next = Map {
+            start: self.start,
+            end: self.end,
+            cells: Grid::new(self.cells.width(), self.cells.height(), Vec::new()),
+        };
+        let size = self.size();
+
+        for pos in self.cells.positions().map(Pos::from) {
+            for bliz in self[&pos].iter() {
+                let pos_next = blow(*bliz, pos, size);
+                map_next[&pos_next].push(*bliz);
+            }
+        }
+        frames::capture(|| map_next.frame());
+        map_next
+    }
+
+    // the walls around the valley, except for the two openings
+    fn is_wall(&self, (i, j): (usize, usize)) -> bool {
+        let (height, width) = self.size();
+        (i == 0 && j != self.start.j || i == height - 1 && j != self.end.j)
+            || j == 0
+            || j == width - 1
+    }
+
+    // blizzards get brighter the more of them share a cell
+    fn frame(&self) -> Grid<Rgb> {
+        const WALL: Rgb = [90, 80, 80];
+        const GROUND: Rgb = [20, 30, 50];
+
+        Grid::from_fn(self.cells.width(), self.cells.height(), |pos| {
+            if self.is_wall(pos) {
+                return WALL;
+            }
+            match self.cells[pos].len() {
+                0 => GROUND,
+                n => {
+                    let shade = (60 * n).min(180) as u8;
+                    [60 + shade / 2, 100 + shade / 2, 70 + shade]
+                }
+            }
+        })
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.cells.height(), self.cells.width())
+    }
+
+    fn distance_to_end(&self, pos: &Pos) -> usize {
+        pos.manhattan(self.end)
+    }
+
+    fn is_free(&self, pos: &Pos) -> bool {
+        let (height, width) = self.size();
+        if pos != &self.start
+            && pos != &self.end
+            && (pos.i == 0 || pos.i == height - 1 || pos.j == 0 || pos.j == width - 1)
+        {
+            false
+        } else {
+            self[pos].is_empty()
+        }
+    }
+
+    fn swap_start_end(&mut self) {
+        mem::swap(&mut self.start, &mut self.end);
+    }
+}
+
+const WALL: Glyph = Glyph::new('#', Tone::Solid);
+const BLIZZARD: Glyph = Glyph::new('>', Tone::Moving);
+const BLIZZARDS: Glyph = Glyph::new('2', Tone::Moving);
+
+impl Render for Map {
+    fn size(&self) -> (usize, usize) {
+        (self.cells.width(), self.cells.height())
+    }
+
+    fn glyph(&self, pos: grid::Pos) -> Glyph {
+        if self.is_wall(pos) {
+            return WALL;
+        }
+        let cell = &self.cells[pos];
+        match cell[..] {
+            [] => Glyph::new('.', Tone::Faint),
+            [bliz] => Glyph::new(bliz.arrow(), Tone::Moving),
+            [_, ..] => Glyph::new(
+                char::from_digit(cell.len() as u32, 10).expect("at most 4 blizzards"),
+                Tone::Moving,
+            ),
+        }
+    }
+
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        &[
+            (WALL, "wall"),
+            (BLIZZARD, "blizzard"),
+            (BLIZZARDS, "blizzards in the same place"),
+        ]
+    }
+}
+
+// the position at each minute, crossing the valley as the blizzards move
+struct Crossing {
+    // the valley at each minute, generated as needed
+    maps: RefCell<Vec<Map>>,
+}
+
+impl SearchProblem for Crossing {
+    type Node = (usize, Pos);
+    type Key = (usize, Pos);
+
+    fn start(&self) -> Self::Node {
+        (0, self.maps.borrow()[0].start)
+    }
+
+    fn key(&self, &node: &Self::Node) -> Self::Key {
+        node
+    }
+
+    fn expand(&self, &(t, pos): &Self::Node) -> Vec<(Self::Node, usize)> {
+        let mut maps = self.maps.borrow_mut();
+        if maps.len() < t + 2 {
+            let next = maps[t].step();
+            trace!("minute {}:\n{}", t + 1, next.render());
+            maps.push(next);
+        }
+        let map_next = &maps[t + 1];
+
+        // actions: move or wait
+        pos.neighbours4()
+            .chain(iter::once(pos))
+            .filter(|&pos| map_next.cells.contains(pos.into()) && map_next.is_free(&pos))
+            .map(|pos| ((t + 1, pos), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, &(_, pos): &Self::Node) -> bool {
+        pos == self.maps.borrow()[0].end
+    }
+
+    fn heuristic(&self, &(_, pos): &Self::Node) -> usize {
+        self.maps.borrow()[0].distance_to_end(&pos)
+    }
+}
+
+// the valley once crossed, and the minutes it took
+fn search_sol(map: Map) -> (Map, usize) {
+    let crossing = Crossing {
+        maps: RefCell::new(vec![map]),
+    };
+    let outcome = Search::new(&crossing)
+        .paths(log::enabled(Level::Debug, module_path!()))
+        .run(Strategy::AStar);
+    debug!("{}", outcome.stats);
+    let found = outcome.found.expect("cannot cross the valley");
+    let path: Vec<String> = found.path.iter().map(|(_, pos)| pos.to_string()).collect();
+    debug!("crossed in {} minutes: {}", found.score, path.join(" "));
+    (
+        crossing.maps.into_inner().swap_remove(found.score),
+        found.score,
+    )
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: usize = 24;
+
+    // valley at time 0
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let (_, trip_1) = search_sol(map.clone());
+        trip_1.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let (mut map, trip_1) = search_sol(map.clone());
+        map.swap_start_end();
+        let (mut map, trip_2) = search_sol(map);
+        map.swap_start_end();
+        let (_, trip_3) = search_sol(map);
+
+        (trip_1 + trip_2 + trip_3).into()
+    }
+}
+
+// whether the valley can be crossed there, back and there again: the
+// blizzards repeat, so a trip is impossible once every position has been
+// reached at every point of their period
+fn crossable(map: &Map) -> bool {
+    let (height, width) = map.size();
+    let (rows, cols) = (height - 2, width - 2);
+    let gcd = |mut a: usize, mut b: usize| {
+        while b > 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let period = rows * cols / gcd(rows, cols);
+    let mut maps = vec![map.clone()];
+    while maps.len() < period {
+        maps.push(maps[maps.len() - 1].step());
+    }
+
+    let mut t = 0;
+    for (from, to) in [
+        (map.start, map.end),
+        (map.end, map.start),
+        (map.start, map.end),
+    ] {
+        let mut seen = HashSet::new();
+        let mut reached = vec![from];
+        while !reached.contains(&to) {
+            t += 1;
+            let next = &maps[t % period];
+            let mut moved = Vec::new();
+            for pos in reached {
+                for pos in iter::once(pos).chain(pos.neighbours4()) {
+                    if pos.i < height
+                        && pos.j < width
+                        && next.is_free(&pos)
+                        && seen.insert((t % period, pos))
+                    {
+                        moved.push(pos);
+                    }
+                }
+            }
+            if moved.is_empty() {
+                return false;
+            }
+            reached = moved;
+        }
+    }
+    true
+}
+
+// a valley `size` tiles wide and a quarter as high, with no blizzards going up
+// or down through the openings, which can always be crossed
+impl Generator for Day24 {
+    const SIZE: usize = 122;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = size.max(5);
+        let height = (width / 4).max(4);
+        loop {
+            let mut res = String::new();
+            for i in 0..height {
+                for j in 0..width {
+                    let c = if i == 0 || i == height - 1 {
+                        let opening = if i == 0 { 1 } else { width - 2 };
+                        if j == opening {
+                            '.'
+                        } else {
+                            '#'
+                        }
+                    } else if j == 0 || j == width - 1 {
+                        '#'
+                    } else if rng.chance(0.5) {
+                        '.'
+                    } else if j == 1 || j == width - 2 {
+                        *rng.choose(&['<', '>'])
+                    } else {
+                        *rng.choose(&['<', '>', '^', 'v'])
+                    };
+                    res.push(c);
+                }
+                res.push('\n');
+            }
+            if crossable(&res.parse().expect("generated an invalid valley")) {
+                break res;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day24::solve(SAMPLE), Ok((18.into(), 54.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(24).read().expect("cannot read input");
+        assert_eq!(Day24::solve(&input), Ok((311.into(), 869.into())));
+    }
+}
@@ -0,0 +1,335 @@
+use std::{fmt::Display, ops::Add, str::FromStr};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+const SNAFU_BASE: isize = 5;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
+pub enum SnafuDigit {
+    DoubleMinus = -2,
+    Minus = -1,
+    #[default]
+    Zero = 0,
+    One = 1,
+    Two = 2,
+}
+
+impl Add<Self> for SnafuDigit {
+    type Output = Snafu;
+    // digits from the least significant
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::DoubleMinus, Self::DoubleMinus) => Snafu(vec![Self::One, Self::Minus]),
+            (Self::DoubleMinus, Self::Minus) => Snafu(vec![Self::Two, Self::Minus]),
+            (Self::DoubleMinus, Self::Zero) => Snafu(vec![Self::DoubleMinus]),
+            (Self::DoubleMinus, Self::One) => Snafu(vec![Self::Minus]),
+            (Self::DoubleMinus, Self::Two) => Snafu(vec![Self::Zero]),
+            //
+            (Self::Minus, Self::Minus) => Snafu(vec![Self::DoubleMinus]),
+            (Self::Minus, Self::Zero) => Snafu(vec![Self::Minus]),
+            (Self::Minus, Self::One) => Snafu(vec![Self::Zero]),
+            (Self::Minus, Self::Two) => Snafu(vec![Self::One]),
+            //
+            (Self::Zero, Self::Zero) => Snafu(vec![Self::Zero]),
+            (Self::Zero, Self::One) => Snafu(vec![Self::One]),
+            (Self::Zero, Self::Two) => Snafu(vec![Self::Two]),
+            //
+            (Self::One, Self::One) => Snafu(vec![Self::Two]),
+            (Self::One, Self::Two) => Snafu(vec![Self::DoubleMinus, Self::One]),
+            //
+            (Self::Two, Self::Two) => Snafu(vec![Self::Minus, Self::One]),
+            //
+            (x, y) => Self::add(y, x),
+        }
+    }
+}
+
+impl From<&SnafuDigit> for isize {
+    fn from(value: &SnafuDigit) -> Self {
+        *value as isize
+    }
+}
+
+impl From<SnafuDigit> for isize {
+    fn from(value: SnafuDigit) -> Self {
+        value as isize
+    }
+}
+
+impl SnafuDigit {
+    fn to_snafu(self) -> Snafu {
+        Snafu(vec![self])
+    }
+}
+
+impl TryFrom<u8> for SnafuDigit {
+    type Error = ParseError;
+
+    fn try_from(digit: u8) -> Result<Self, Self::Error> {
+        match digit {
+            b'2' => Ok(Self::Two),
+            b'1' => Ok(Self::One),
+            b'0' => Ok(Self::Zero),
+            b'-' => Ok(Self::Minus),
+            b'=' => Ok(Self::DoubleMinus),
+            _ => Err(ParseError::new(
+                0,
+                &String::from_utf8_lossy(&[digit]),
+                "unknown digit",
+            )),
+        }
+    }
+}
+
+impl Display for SnafuDigit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::DoubleMinus => '=',
+                Self::Minus => '-',
+                Self::Zero => '0',
+                Self::One => '1',
+                Self::Two => '2',
+            }
+        )
+    }
+}
+
+// saved from LSD to MSD
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Snafu(Vec<SnafuDigit>);
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for d in self.0.iter().rev() {
+            write!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Snafu {
+    fn default() -> Self {
+        Snafu(vec![SnafuDigit::default()])
+    }
+}
+
+impl Add<Self> for Snafu {
+    type Output = Snafu;
+    fn add(self, rhs: Self) -> Self::Output {
+        // longest number first
+        let (x, y) = if self.0.len() > rhs.0.len() {
+            (&self.0, &rhs.0)
+        } else {
+            (&rhs.0, &self.0)
+        };
+        let mut carry = Snafu(vec![]);
+        let mut res = Snafu(vec![]);
+        for i in 0.. {
+            if i >= x.len() && carry.0.is_empty() {
+                break;
+            }
+
+            carry = match (x.get(i), y.get(i), carry) {
+                (Some(l), Some(r), carry) if carry.0.is_empty() => *l + *r,
+                (Some(l), Some(r), carry) => *l + *r + carry,
+                (Some(_), None, carry) if carry.0.is_empty() => {
+                    res.0.extend_from_slice(&x[i..]);
+                    return res;
+                }
+                (Some(l), None, carry) => l.to_snafu() + carry,
+                (None, Some(_), carry) if carry.0.is_empty() => {
+                    res.0.extend_from_slice(&y[i..]);
+                    return res;
+                }
+                (None, Some(r), carry) => r.to_snafu() + carry,
+                (None, None, carry) => {
+                    res.0.extend(carry.0);
+                    return res;
+                }
+            };
+            let digit = carry.0.remove(0);
+            res.0.push(digit);
+        }
+        res
+    }
+}
+
+// digits from the most significant
+impl FromIterator<SnafuDigit> for Snafu {
+    fn from_iter<I: IntoIterator<Item = SnafuDigit>>(iter: I) -> Self {
+        let mut digits: Vec<SnafuDigit> = iter.into_iter().collect();
+        digits.reverse();
+        Self(digits)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::new(0, s, "empty number"));
+        }
+        if let Some(i) = s.find(|c| !"210-=".contains(c)) {
+            let len = s[i..].chars().next().map_or(1, char::len_utf8);
+            return Err(ParseError::new(i, &s[i..i + len], "unknown digit"));
+        }
+        s.bytes().map(SnafuDigit::try_from).collect()
+    }
+}
+
+impl TryFrom<&Snafu> for isize {
+    type Error = String;
+
+    // from the most significant digit, so that leading zeros cannot overflow
+    fn try_from(value: &Snafu) -> Result<Self, Self::Error> {
+        value.0.iter().rev().try_fold(0, |res: isize, &digit| {
+            res.checked_mul(SNAFU_BASE)
+                .and_then(|res| res.checked_add(digit.into()))
+                .ok_or_else(|| format!("{} does not fit in {} bits", value, isize::BITS))
+        })
+    }
+}
+
+impl TryFrom<Snafu> for isize {
+    type Error = String;
+
+    fn try_from(value: Snafu) -> Result<Self, Self::Error> {
+        (&value).try_into()
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: usize = 25;
+
+    // fuel requirements
+    type Input = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| line.text.parse().map_err(|e| line.locate(line.text, e)))
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        let mut acc = Snafu::default();
+        for snafu in numbers {
+            acc = acc + snafu.clone();
+        }
+        acc.to_string().into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+// `size` numbers of up to 20 digits
+impl Generator for Day25 {
+    const SIZE: usize = 120;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut number = rng.choose(&["1", "2"]).to_string();
+                for _ in 0..rng.below(20) {
+                    number.push(*rng.choose(&['=', '-', '0', '1', '2']));
+                }
+                number + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    fn test_conversion(dec: isize, snafu: &str) {
+        let res = isize::try_from(snafu.parse::<Snafu>().expect("invalid snafu"));
+        assert_eq!(res, Ok(dec));
+    }
+
+    #[test]
+    fn conversion() {
+        test_conversion(1, "1");
+        test_conversion(2, "2");
+        test_conversion(3, "1=");
+        test_conversion(4, "1-");
+        test_conversion(5, "10");
+        test_conversion(8, "2=");
+        test_conversion(10, "20");
+        test_conversion(15, "1=0");
+        test_conversion(20, "1-0");
+        test_conversion(2022, "1=11-2");
+        test_conversion(12345, "1-0---0");
+        test_conversion(314159265, "1121-1110-1=0");
+        test_conversion(-3, "-2");
+        test_conversion(1, &format!("{}1", "0".repeat(40)));
+    }
+
+    #[test]
+    fn overflow() {
+        // 5^27 fits, twice as much does not
+        let big = |first: &str| {
+            isize::try_from(
+                format!("{}{}", first, "0".repeat(27))
+                    .parse::<Snafu>()
+                    .unwrap(),
+            )
+        };
+        assert_eq!(big("1"), Ok(5_isize.pow(27)));
+        assert!(big("2").is_err());
+        assert!(big("=").is_err());
+        assert_eq!(
+            big("1=").unwrap_err(),
+            format!("1={} does not fit in 64 bits", "0".repeat(27))
+        );
+    }
+
+    #[test]
+    fn unknown_digit() {
+        let res: Result<Snafu, _> = b"1=3".iter().map(|&b| SnafuDigit::try_from(b)).collect();
+        assert_eq!(res.unwrap_err().reason, "unknown digit");
+        let err = "1-\u{e4}".parse::<Snafu>().unwrap_err();
+        assert_eq!(err.text, "\u{e4}");
+    }
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day25::solve(SAMPLE), Ok(("2=-1=0".into(), Answer::None)));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(25).read().expect("cannot read input");
+        assert_eq!(
+            Day25::solve(&input),
+            Ok(("2----0=--1122=0=0021".into(), Answer::None))
+        );
+    }
+}
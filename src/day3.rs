@@ -0,0 +1,148 @@
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+fn priority(c: char) -> usize {
+    match c {
+        'a'..='z' => c as usize - 'a' as usize + 1,
+        'A'..='Z' => c as usize - 'A' as usize + 27,
+        _ => panic!(),
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: usize = 3;
+
+    // one rucksack per line
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|l| {
+                if let Some(i) = l.text.find(|c: char| !c.is_ascii_alphabetic()) {
+                    return Err(l.error(&l.text[i..i + 1], "invalid item"));
+                }
+                if l.text.len() % 2 != 0 {
+                    return Err(l.error(l.text, "odd number of items"));
+                }
+                Ok(l.text.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        let tot_prio: usize = rucksacks
+            .iter()
+            .map(|l| {
+                assert_eq!(l.len() % 2, 0);
+                l[..l.len() / 2]
+                    .chars()
+                    .find(|&c| l[l.len() / 2..].chars().any(|other| c == other))
+                    .unwrap()
+            })
+            .map(priority)
+            .sum();
+        tot_prio.into()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        let mut lines = rucksacks.iter();
+        let mut tot_prio: usize = 0;
+
+        loop {
+            let l = lines.next();
+            let Some(l1) = l else {
+                break;
+            };
+            let l2 = lines.next().unwrap();
+            let l3 = lines.next().unwrap();
+
+            let c = l1
+                .chars()
+                .find(|&c1| l2.chars().any(|c2| c1 == c2) && l3.chars().any(|c3| c1 == c3))
+                .unwrap();
+
+            tot_prio += priority(c);
+        }
+        tot_prio.into()
+    }
+}
+
+// about `size` rucksacks, in groups of three
+impl Generator for Day3 {
+    const SIZE: usize = 300;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const ITEMS: &[char] = &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H',
+            'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y',
+            'Z',
+        ];
+
+        let mut res = String::new();
+        for _ in 0..size.div_ceil(3).max(1) {
+            let badge = *rng.choose(ITEMS);
+            let with_badge = rng.below(3);
+            let mut group: Vec<String> = Vec::new();
+            for elf in 0..3 {
+                // the badge is the only item all three elves carry
+                let mut pool: Vec<char> = ITEMS
+                    .iter()
+                    .copied()
+                    .filter(|&c| {
+                        c != badge && !(elf == 2 && group[0].contains(c) && group[1].contains(c))
+                    })
+                    .collect();
+                rng.shuffle(&mut pool);
+                let shared = pool[0];
+                let (left, right) = pool[1..].split_at(pool.len() / 2);
+
+                let len = rng.below(13) + 4;
+                let mut half = |items: &[char], badge: Option<char>| {
+                    let mut half: Vec<char> = (0..len).map(|_| *rng.choose(items)).collect();
+                    half[0] = shared;
+                    half[1] = badge.unwrap_or(half[1]);
+                    rng.shuffle(&mut half);
+                    half
+                };
+                let mut rucksack = half(left, Some(badge).filter(|_| with_badge == elf));
+                rucksack.extend(half(right, Some(badge).filter(|_| with_badge != elf)));
+                group.push(rucksack.into_iter().collect());
+            }
+            for rucksack in group {
+                res.push_str(&rucksack);
+                res.push('\n');
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day3::solve(SAMPLE), Ok((157.into(), 70.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(3).read().expect("cannot read input");
+        assert_eq!(Day3::solve(&input), Ok((7763.into(), 2569.into())));
+    }
+}
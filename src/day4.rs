@@ -0,0 +1,81 @@
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+// section assignments of a pair of elves: (s1, e1, s2, e2)
+type Pair = (usize, usize, usize, usize);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|l| l.scan("{s1}-{e1},{s2}-{e2}"))
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        let tot_overlap: usize = pairs
+            .iter()
+            .filter(|&&(s1, e1, s2, e2)| (s1 <= s2 && e2 <= e1) || (s2 <= s1 && e1 <= e2))
+            .count();
+        tot_overlap.into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        let tot_overlap: usize = pairs
+            .iter()
+            .filter(|&&(s1, e1, s2, e2)| (s1 <= s2 && s2 <= e1) || (s2 <= s1 && s1 <= e2))
+            .count();
+        tot_overlap.into()
+    }
+}
+
+// `size` pairs of sections
+impl Generator for Day4 {
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        (0..size.max(1))
+            .map(|_| {
+                let (s1, e1) = range();
+                let (s2, e2) = range();
+                format!("{}-{},{}-{}\n", s1, e1, s2, e2)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day4::solve(SAMPLE), Ok((2.into(), 4.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(4).read().expect("cannot read input");
+        assert_eq!(Day4::solve(&input), Ok((471.into(), 888.into())));
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::VecDeque;
+
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+// back is top, front is bottom
+type Stacks = Vec<VecDeque<char>>;
+
+// (num, src, dst), zero-based
+type Move = (usize, usize, usize);
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: usize = 5;
+
+    type Input = (Stacks, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(Self::DAY, input);
+
+        // Parse input
+        let mut rows: Vec<&str> = vec![];
+        let labels = loop {
+            let l = lines
+                .next()
+                .ok_or_else(|| ParseError::input(Self::DAY, "missing stack labels"))?;
+            if !l.text.contains('[') {
+                break l;
+            }
+            rows.push(l.text);
+        };
+
+        // the label line tells how many stacks there are
+        let n = labels.text.split_whitespace().count();
+        let mut stacks: Stacks = vec![VecDeque::new(); n];
+        for l in rows {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                // trailing spaces may have been trimmed
+                match l.chars().nth(4 * i + 1) {
+                    None | Some(' ') => (),
+                    Some(cr) => {
+                        stack.push_front(cr);
+                    }
+                }
+            }
+        }
+        match lines.next() {
+            Some(l) if !l.text.is_empty() => return Err(l.error(l.text, "expected an empty line")),
+            _ => (),
+        }
+        debug!("initial stacks:\n{}", show(&stacks));
+
+        // Moves
+        let moves = lines
+            .map(|l| {
+                let (num, src, dst): (usize, usize, usize) =
+                    l.scan("move {count} from {src} to {dst}")?;
+                if !(1..=n).contains(&src) || !(1..=n).contains(&dst) {
+                    return Err(l.error(l.text, "no such stack"));
+                }
+                Ok((num, src - 1, dst - 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        // one crate at a time
+        for &(num, src, dst) in moves {
+            for _ in 0..num {
+                let el = stacks[src].pop_back().unwrap();
+                stacks[dst].push_back(el);
+            }
+        }
+        top_crates(&stacks).into()
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+        // multiple crates at a time
+        for &(num, src, dst) in moves {
+            let off = stacks[src].len() - num;
+            let els = stacks[src].split_off(off);
+            stacks[dst].extend(els);
+        }
+        top_crates(&stacks).into()
+    }
+}
+
+fn show(stacks: &Stacks) -> String {
+    let lines: Vec<String> = stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| format!("{}: {:?}", i, stack))
+        .collect();
+    lines.join("\n")
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+    debug!("final stacks:\n{}", show(stacks));
+
+    stacks.iter().map(|s| s.back().unwrap_or(&' ')).collect()
+}
+
+// up to nine stacks and `size` moves, which never take more crates than there are
+impl Generator for Day5 {
+    const SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = rng.below(7) + 3;
+        let heights: Vec<usize> = (0..n).map(|_| rng.below(8) + 1).collect();
+        let mut res = String::new();
+        for row in (0..*heights.iter().max().unwrap()).rev() {
+            let crates: Vec<String> = heights
+                .iter()
+                .map(|&h| {
+                    if h > row {
+                        format!("[{}]", (b'A' + rng.below(26) as u8) as char)
+                    } else {
+                        "   ".to_string()
+                    }
+                })
+                .collect();
+            res.push_str(crates.join(" ").trim_end());
+            res.push('\n');
+        }
+        let labels: Vec<String> = (1..=n).map(|i| format!(" {} ", i)).collect();
+        res.push_str(&labels.join(" "));
+        res.push_str("\n\n");
+
+        let mut heights = heights;
+        for _ in 0..size {
+            let src = loop {
+                let src = rng.below(n);
+                if heights[src] > 0 {
+                    break src;
+                }
+            };
+            let dst = (src + 1 + rng.below(n - 1)) % n;
+            let num = rng.below(heights[src]) + 1;
+            heights[src] -= num;
+            heights[dst] += num;
+            res.push_str(&format!("move {} from {} to {}\n", num, src + 1, dst + 1));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day5::solve(SAMPLE), Ok(("CMZ".into(), "MCD".into())));
+    }
+
+    #[test]
+    fn stack_count() {
+        // two stacks, with the trailing spaces trimmed
+        let input = "[A]\n[B] [C]\n 1   2\n\nmove 1 from 1 to 2";
+        assert_eq!(Day5::solve(input), Ok(("BA".into(), "BA".into())));
+        assert!(Day5::parse("[A]\n 1\n\nmove 1 from 1 to 2").is_err());
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(5).read().expect("cannot read input");
+        assert_eq!(
+            Day5::solve(&input),
+            Ok(("JDTMRWCQJ".into(), "VHJDDCWRD".into()))
+        );
+    }
+}
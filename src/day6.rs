@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+fn find_marker(signal: &str, mark_len: usize) -> usize {
+    let mut off: usize = 0;
+    let mut seq: VecDeque<char> = Default::default();
+
+    for (i, c) in signal.chars().enumerate() {
+        seq.push_back(c);
+        if seq.len() > mark_len {
+            seq.pop_front();
+            let mut unique = true;
+            for &c in seq.iter() {
+                unique &= seq.iter().filter(|&&d| d == c).count() == 1;
+            }
+            if unique {
+                off = i;
+                break;
+            }
+        }
+    }
+    off + 1
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: usize = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let l = parse::lines(Self::DAY, input)
+            .next()
+            .ok_or_else(|| ParseError::input(Self::DAY, "empty input"))?;
+        Ok(l.text.to_string())
+    }
+
+    fn part1(signal: &Self::Input) -> Answer {
+        find_marker(signal, 4).into()
+    }
+
+    fn part2(signal: &Self::Input) -> Answer {
+        find_marker(signal, 14).into()
+    }
+}
+
+// a signal of `size` characters, with the markers somewhere in the middle
+impl Generator for Day6 {
+    const SIZE: usize = 4096;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(32);
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut letters);
+
+        // too few different letters for a marker, then enough for the first one
+        let mut res: Vec<char> = (0..size / 4).map(|_| *rng.choose(&letters[..3])).collect();
+        res.extend((0..size / 4).map(|_| *rng.choose(&letters[..13])));
+        res.extend(&letters[..14]);
+        while res.len() < size {
+            res.push(*rng.choose(&letters));
+        }
+        res.truncate(size);
+        res.into_iter().chain(['\n']).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day6::solve(SAMPLE), Ok((7.into(), 19.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(6).read().expect("cannot read input");
+        assert_eq!(Day6::solve(&input), Ok((1566.into(), 2265.into())));
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ops::Deref,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+#[derive(Debug)]
+pub struct Dir {
+    parent: Option<Weak<RefCell<Dir>>>,
+    children: HashMap<String, Node>,
+}
+
+impl Dir {
+    fn new(parent: Option<Weak<RefCell<Dir>>>) -> Self {
+        Self {
+            parent,
+            children: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    size: usize,
+}
+
+impl File {
+    fn new(size: usize) -> Self {
+        Self { size }
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Dir(Rc<RefCell<Dir>>),
+    File(Rc<RefCell<File>>),
+}
+
+const THRESHOLD: usize = 100000;
+const DISK_SIZE: usize = 70000000;
+const UPDATE_SIZE: usize = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: usize = 7;
+
+    // root of the filesystem
+    type Input = Rc<RefCell<Dir>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let root = Rc::new(RefCell::new(Dir::new(None)));
+        let mut cur = Rc::downgrade(&root);
+
+        let mut lines = parse::lines(Self::DAY, input).peekable();
+        while let Some(line) = lines.next() {
+            let mut toks = line.text.split(' ');
+            match toks.next() {
+                Some("$") => (),
+                _ => return Err(line.error(line.text, "expected a command")),
+            }
+            match toks.next() {
+                Some("cd") => {
+                    match toks.next() {
+                        Some("/") => {
+                            // back to root
+                            cur = Rc::downgrade(&root);
+                        }
+                        Some("..") => {
+                            // up one level
+                            cur = cur
+                                .upgrade()
+                                .unwrap()
+                                .borrow()
+                                .parent
+                                .as_ref()
+                                .cloned()
+                                .unwrap_or_else(|| Rc::downgrade(&root));
+                        }
+                        Some(dir) => {
+                            // down one level
+                            let _cur = cur.upgrade().unwrap();
+                            let _cur = _cur.borrow_mut();
+                            match _cur.children.get(dir) {
+                                Some(Node::Dir(d)) => cur = Rc::downgrade(d),
+                                Some(Node::File(_)) => return Err(line.error(dir, "cd: not a dir")),
+                                None => return Err(line.error(dir, "cd: inexistent")),
+                            }
+                        }
+                        None => return Err(line.missing("cd argument")),
+                    }
+                }
+                Some("ls") => {
+                    while let Some(line) = lines.next_if(|l| !l.text.starts_with("$ ")) {
+                        let mut toks = line.text.split(' ');
+                        match toks.next() {
+                            Some("dir") => {
+                                let Some(dir) = toks.next() else {
+                                    return Err(line.missing("dir name"));
+                                };
+                                let node =
+                                    Node::Dir(Rc::new(RefCell::new(Dir::new(Some(cur.clone())))));
+                                cur.upgrade()
+                                    .unwrap()
+                                    .borrow_mut()
+                                    .children
+                                    .insert(dir.to_string(), node);
+                            }
+                            Some(size) => {
+                                let size: usize = line.parse(size)?;
+                                let Some(file) = toks.next() else {
+                                    return Err(line.missing("file name"));
+                                };
+                                let node = Node::File(Rc::new(RefCell::new(File::new(size))));
+                                cur.upgrade()
+                                    .unwrap()
+                                    .borrow_mut()
+                                    .children
+                                    .insert(file.to_string(), node);
+                            }
+                            None => return Err(line.missing("entry")),
+                        }
+                    }
+                }
+                Some(cmd) => return Err(line.error(cmd, "unknown command")),
+                None => return Err(line.missing("command")),
+            }
+        }
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input) -> Answer {
+        let mut dirsizes: Vec<usize> = vec![];
+        dir_size(root.borrow(), &mut dirsizes);
+
+        let tot_size_below_thresh: usize = dirsizes.iter().filter(|&&s| s <= THRESHOLD).sum();
+        tot_size_below_thresh.into()
+    }
+
+    fn part2(root: &Self::Input) -> Answer {
+        let mut dirsizes: Vec<usize> = vec![];
+        let root_size = dir_size(root.borrow(), &mut dirsizes);
+
+        let free_space = DISK_SIZE - root_size;
+        let min_to_free = UPDATE_SIZE - free_space;
+
+        let min_size_to_del = dirsizes
+            .iter()
+            .filter(|&&s| s >= min_to_free)
+            .min()
+            .unwrap_or(&0);
+        (*min_size_to_del).into()
+    }
+}
+
+fn dir_size<D: Deref<Target = Dir>>(dir: D, dirsizes: &mut Vec<usize>) -> usize {
+    let mut dirsize: usize = 0;
+    for (_, node) in dir.children.iter() {
+        match node {
+            Node::File(f) => dirsize += f.borrow().size,
+            Node::Dir(d) => dirsize += dir_size(d.borrow(), dirsizes),
+        }
+    }
+    dirsizes.push(dirsize);
+    dirsize
+}
+
+// `size` directories, filled enough that the update needs some space freed
+impl Generator for Day7 {
+    const SIZE: usize = 180;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        struct GenDir {
+            dirs: Vec<(String, usize)>,
+            files: Vec<(String, usize)>,
+        }
+
+        // a name not used yet in a directory
+        fn name(rng: &mut Rng, taken: &[String], ext: bool) -> String {
+            loop {
+                let len = rng.below(8) + 1;
+                let mut name = rng.word(len);
+                if ext && rng.chance(0.6) {
+                    let len = rng.below(3) + 1;
+                    name = format!("{}.{}", name, rng.word(len));
+                }
+                if !taken.contains(&name) {
+                    break name;
+                }
+            }
+        }
+        let taken = |dir: &GenDir| -> Vec<String> {
+            dir.dirs
+                .iter()
+                .chain(&dir.files)
+                .map(|(n, _)| n.clone())
+                .collect()
+        };
+
+        let mut dirs: Vec<GenDir> = Vec::new();
+        let mut weights = Vec::new();
+        for i in 0..size.max(1) {
+            dirs.push(GenDir {
+                dirs: Vec::new(),
+                files: Vec::new(),
+            });
+            if i > 0 {
+                let parent = rng.below(i);
+                let name = name(rng, &taken(&dirs[parent]), false);
+                dirs[parent].dirs.push((name, i));
+            }
+            let files = if i == 0 {
+                rng.below(5) + 1
+            } else {
+                rng.below(6)
+            };
+            for _ in 0..files {
+                let name = name(rng, &taken(&dirs[i]), true);
+                let weight = rng.below(1000) + 1;
+                dirs[i].files.push((name, weight));
+                weights.push(weight);
+            }
+        }
+
+        // spread a total between the minimum needed and the disk size
+        let total =
+            rng.range(UPDATE_SIZE as isize + 10000000..=DISK_SIZE as isize - 2000000) as usize;
+        let sum: usize = weights.iter().sum();
+        for dir in dirs.iter_mut() {
+            for (_, size) in dir.files.iter_mut() {
+                *size = (*size * total / sum).max(1);
+            }
+        }
+
+        fn list(dirs: &[GenDir], i: usize, rng: &mut Rng, res: &mut String) {
+            res.push_str("$ ls\n");
+            let mut entries: Vec<String> = dirs[i]
+                .dirs
+                .iter()
+                .map(|(name, _)| format!("dir {}", name))
+                .collect();
+            entries.extend(
+                dirs[i]
+                    .files
+                    .iter()
+                    .map(|(name, size)| format!("{} {}", size, name)),
+            );
+            rng.shuffle(&mut entries);
+            for entry in entries {
+                res.push_str(&entry);
+                res.push('\n');
+            }
+            for (name, sub) in &dirs[i].dirs {
+                res.push_str(&format!("$ cd {}\n", name));
+                list(dirs, *sub, rng, res);
+                res.push_str("$ cd ..\n");
+            }
+        }
+
+        let mut res = String::from("$ cd /\n");
+        list(&dirs, 0, rng, &mut res);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day7::solve(SAMPLE), Ok((95437.into(), 24933642.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(7).read().expect("cannot read input");
+        assert_eq!(Day7::solve(&input), Ok((1543140.into(), 1117448.into())));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    debug,
+    gen::{Generator, Rng},
+    grid::Grid,
+    render::{Glyph, Render, Tile, Tone},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tree {
+    height: usize,
+    visible: bool,
+}
+
+const HIDDEN: Glyph = Glyph::new('.', Tone::Faint);
+
+impl Tile for Tree {
+    fn glyph(&self) -> Glyph {
+        if self.visible {
+            let ch = char::from_digit(self.height as u32, 10).expect("heights are digits");
+            Glyph::new(ch, Tone::Marked)
+        } else {
+            HIDDEN
+        }
+    }
+
+    fn legend() -> &'static [(Glyph, &'static str)] {
+        const VISIBLE: Glyph = Glyph::new('9', Tone::Marked);
+        &[
+            (VISIBLE, "visible tree, by height"),
+            (HIDDEN, "hidden tree"),
+        ]
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: usize = 8;
+
+    type Input = Grid<Tree>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // read data
+        Grid::parse(Self::DAY, input, |c| {
+            let height = c.to_digit(10)? as usize;
+            Some(Tree {
+                height,
+                visible: false,
+            })
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        for i in 0..grid.height() {
+            let row = grid.row_positions(i);
+            // check from left
+            check_visibility(&mut grid, row.clone());
+            // check from right
+            check_visibility(&mut grid, row.rev());
+        }
+        for j in 0..grid.width() {
+            let col = grid.col_positions(j);
+            // check from up
+            check_visibility(&mut grid, col.clone());
+            // check from down
+            check_visibility(&mut grid, col.rev());
+        }
+
+        debug!("visible trees:\n{}", grid.render());
+
+        let visible_count: usize = grid.iter().filter(|t| t.visible).count();
+        visible_count.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        // Scenic score
+        let best_score = grid
+            .positions()
+            .map(|pos| scenic_score(grid, pos))
+            .max()
+            .unwrap_or(0);
+        best_score.into()
+    }
+}
+
+fn check_visibility<I: Iterator<Item = (usize, usize)>>(grid: &mut Grid<Tree>, positions: I) {
+    positions.fold(None, |acc, pos| {
+        let tree = &mut grid[pos];
+        tree.visible |= match acc {
+            None => true,
+            Some(max_height) => tree.height > max_height,
+        };
+        match acc {
+            None => Some(tree.height),
+            Some(max_height) => Some(max_height.max(tree.height)),
+        }
+    });
+}
+
+fn scenic_score(grid: &Grid<Tree>, pos: (usize, usize)) -> usize {
+    let height = grid[pos].height;
+    // check in all directions
+    [(0, 1), (0, -1), (1, 0), (-1, 0)]
+        .into_iter()
+        .map(|dir| view_distance(grid, height, grid.ray(pos, dir)))
+        .product()
+}
+
+fn view_distance<I: Iterator<Item = (usize, usize)>>(
+    grid: &Grid<Tree>,
+    height: usize,
+    positions: I,
+) -> usize {
+    let mut dist: usize = 0;
+    for pos in positions {
+        dist += 1;
+        if height <= grid[pos].height {
+            break;
+        }
+    }
+    dist
+}
+
+// a forest of `size` by `size` trees
+impl Generator for Day8 {
+    const SIZE: usize = 99;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                let row: String = (0..size)
+                    .map(|_| char::from(b'0' + rng.below(10) as u8))
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day8::solve(SAMPLE), Ok((21.into(), 8.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(8).read().expect("cannot read input");
+        assert_eq!(Day8::solve(&input), Ok((1816.into(), 383520.into())));
+    }
+}
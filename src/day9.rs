@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use crate::{
+    gen::{Generator, Rng},
+    geometry::{Direction, Point2},
+    parse, Answer, ParseError, Solution,
+};
+
+type Pos = Point2<i32>;
+
+// tail positions visited by the given knot of a 10-knot rope
+fn visited(moves: &[(Direction, usize)], tracked: usize) -> usize {
+    let mut visited = HashSet::<Pos>::new();
+
+    let mut knots = [Pos::ORIGIN; 10];
+
+    for &(dir, cnt) in moves {
+        for _ in 0..cnt {
+            // move head
+            knots[0] += dir;
+            // follow with the other knots
+            for k in 1..10 {
+                let knot = knots[k];
+                let prev = knots[k - 1];
+                // step towards the previous knot unless touching it
+                if knot.chebyshev(prev) > 1 {
+                    knots[k] = knot + (prev - knot).signum();
+                }
+                if k == tracked {
+                    visited.insert(knots[k]);
+                }
+            }
+        }
+    }
+    visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: usize = 9;
+
+    // direction and number of steps of each head motion
+    type Input = Vec<(Direction, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| {
+                let mut toks = line.text.split(' ');
+                let dir = toks.next().ok_or_else(|| line.missing("direction"))?;
+                let cnt: usize = line.parse(toks.next().ok_or_else(|| line.missing("count"))?)?;
+                let dir = match dir {
+                    "U" => Direction::N,
+                    "D" => Direction::S,
+                    "L" => Direction::W,
+                    "R" => Direction::E,
+                    _ => return Err(line.error(dir, "unknown dir")),
+                };
+                Ok((dir, cnt))
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        visited(moves, 1).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Answer {
+        visited(moves, 9).into()
+    }
+}
+
+// `size` motions of the head
+impl Generator for Day9 {
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&["U", "D", "L", "R"]),
+                    rng.range(1..=20)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day9::solve(SAMPLE), Ok((13.into(), 1.into())));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day(9).read().expect("cannot read input");
+        assert_eq!(Day9::solve(&input), Ok((6522.into(), 2717.into())));
+    }
+}
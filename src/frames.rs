@@ -0,0 +1,485 @@
+//! Captures the steps of a simulation as image frames.
+//!
+//! Solutions call [`capture`] at every step with a closure drawing the current
+//! state, which only runs if capturing was enabled with [`init`] and the step
+//! is not skipped. Frames of each part go to their own sequence, named after
+//! the day and the part (`day14-part1`): a directory of numbered PPM or PNG
+//! images, or a single animated GIF.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
+
+use crate::{error, grid::Grid, Part};
+
+pub type Rgb = [u8; 3];
+
+/// Colour of a cell when drawn into a frame.
+pub trait Paint {
+    fn paint(&self) -> Rgb;
+}
+
+/// Draws every cell of `grid` with its own colour.
+pub fn render<T: Paint>(grid: &Grid<T>) -> Grid<Rgb> {
+    grid.map(Paint::paint)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Self::Ppm),
+            "png" => Ok(Self::Png),
+            "gif" => Ok(Self::Gif),
+            _ => Err(format!("expected ppm, png or gif, got '{}'", s)),
+        }
+    }
+}
+
+/// Part of the frames to keep, in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Crop {
+    type Err = String;
+
+    // x,y,width,height
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| format!("expected x,y,width,height, got '{}'", s))?;
+        match values[..] {
+            [x, y, width, height]
+                if width > 0
+                    && height > 0
+                    && x.checked_add(width).is_some()
+                    && y.checked_add(height).is_some() =>
+            {
+                Ok(Self {
+                    x,
+                    y,
+                    width,
+                    height,
+                })
+            }
+            _ => Err(format!("expected x,y,width,height, got '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub dir: PathBuf,
+    pub format: Format,
+    /// Only every `skip`-th step is captured.
+    pub skip: usize,
+    pub crop: Option<Crop>,
+    /// Side of the square of pixels drawn for each cell.
+    pub scale: usize,
+}
+
+impl Options {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            format: Format::Png,
+            skip: 1,
+            crop: None,
+            scale: 1,
+        }
+    }
+
+    /// Checks that the steps can be counted and the frames drawn, within
+    /// [`MAX_PIXELS`] once cropped and scaled.
+    pub fn check(&self) -> Result<(), String> {
+        if self.skip == 0 || self.scale == 0 {
+            return Err("frame skip and scale must be positive".to_string());
+        }
+        let (width, height) = self.crop.map_or((1, 1), |crop| (crop.width, crop.height));
+        if pixels(width, height, self.scale).is_none() {
+            return Err(format!(
+                "frames of {}x{} cells at scale {} would have more than {} pixels",
+                width, height, self.scale, MAX_PIXELS
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Most pixels in a frame, larger ones are refused.
+pub const MAX_PIXELS: usize = 1 << 26;
+
+// pixels of a frame of width x height cells, if there are not too many
+fn pixels(width: usize, height: usize, scale: usize) -> Option<usize> {
+    let count = width
+        .checked_mul(scale)?
+        .checked_mul(height.checked_mul(scale)?)?;
+    (count <= MAX_PIXELS).then_some(count)
+}
+
+// hundredths of a second between two frames of a GIF
+const GIF_DELAY: u16 = 4;
+
+#[derive(Default)]
+struct Sequence {
+    steps: usize,
+    frames: usize,
+    // size of the first frame, which all the others are fitted to
+    size: Option<(usize, usize)>,
+    gif: Option<GifWriter<BufWriter<File>>>,
+    failed: bool,
+}
+
+struct Recorder {
+    options: Options,
+    // each sequence has its own lock, so that days running on other threads
+    // are not held up by the frames of this one
+    sequences: Mutex<HashMap<String, Arc<Mutex<Sequence>>>>,
+}
+
+impl Recorder {
+    fn sequence(&self, name: &str) -> Arc<Mutex<Sequence>> {
+        let mut sequences = self.sequences.lock().expect("poisoned frame recorder");
+        Arc::clone(sequences.entry(name.to_string()).or_default())
+    }
+}
+
+static RECORDER: OnceLock<Recorder> = OnceLock::new();
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CURRENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Enables capturing for the rest of the program, can only be done once.
+pub fn init(options: Options) -> Result<(), String> {
+    options.check()?;
+    fs::create_dir_all(&options.dir).map_err(|e| format!("{}: {}", options.dir.display(), e))?;
+    RECORDER
+        .set(Recorder {
+            options,
+            sequences: Mutex::new(HashMap::new()),
+        })
+        .map_err(|_| "frame capture was already initialized".to_string())?;
+    ENABLED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sends the frames captured on this thread to the sequence of a day and part.
+pub fn begin(day: usize, part: Part) {
+    if enabled() {
+        CURRENT.with(|current| *current.borrow_mut() = Some(format!("day{}-part{}", day, part)));
+    }
+}
+
+/// Records one step of the current sequence, drawing it with `frame` unless
+/// capturing is disabled or the step is skipped.
+pub fn capture<F: FnOnce() -> Grid<Rgb>>(frame: F) {
+    if !enabled() {
+        return;
+    }
+    let Some(name) = CURRENT.with(|current| current.borrow().clone()) else {
+        return;
+    };
+    let recorder = RECORDER.get().expect("frame capture not initialized");
+    let sequence = recorder.sequence(&name);
+
+    // the frame is drawn and encoded without the lock
+    let number = {
+        let mut sequence = sequence.lock().expect("poisoned frame sequence");
+        sequence.steps += 1;
+        if sequence.failed || !(sequence.steps - 1).is_multiple_of(recorder.options.skip) {
+            return;
+        }
+        sequence.frames += 1;
+        sequence.frames
+    };
+    if let Err(e) = record(&sequence, &name, number, frame(), &recorder.options) {
+        error!("cannot write frame of {}: {}", name, e);
+        sequence.lock().expect("poisoned frame sequence").failed = true;
+    }
+}
+
+/// Completes the animations, to be called once all the solutions are done.
+pub fn finish() -> io::Result<()> {
+    let Some(recorder) = RECORDER.get() else {
+        return Ok(());
+    };
+    let sequences = recorder.sequences.lock().expect("poisoned frame recorder");
+    for sequence in sequences.values() {
+        let gif = sequence.lock().expect("poisoned frame sequence").gif.take();
+        if let Some(gif) = gif {
+            gif.finish()?.flush()?;
+        }
+    }
+    Ok(())
+}
+
+// writes the frame numbered `number` of a sequence, only locking it to
+// get the size of the frames and to add to the animation
+fn record(
+    sequence: &Mutex<Sequence>,
+    name: &str,
+    number: usize,
+    frame: Grid<Rgb>,
+    options: &Options,
+) -> io::Result<()> {
+    let lock = || sequence.lock().expect("poisoned frame sequence");
+    let frame = match options.crop {
+        Some(crop) => fit(&frame, crop.x, crop.y, crop.width, crop.height),
+        None => frame,
+    };
+    let (width, height) = *lock().size.get_or_insert((frame.width(), frame.height()));
+    let frame = scale(&fit(&frame, 0, 0, width, height), options.scale)?;
+
+    match options.format {
+        Format::Gif => {
+            let encoded = gif_frame(&frame)?;
+            let mut sequence = lock();
+            if sequence.gif.is_none() {
+                let file = File::create(options.dir.join(format!("{}.gif", name)))?;
+                sequence.gif = Some(GifWriter::new(
+                    BufWriter::new(file),
+                    frame.width(),
+                    frame.height(),
+                )?);
+            }
+            sequence.gif.as_mut().expect("gif writer").frame(&encoded)
+        }
+        format => {
+            let dir = options.dir.join(name);
+            let ext = if format == Format::Ppm { "ppm" } else { "png" };
+            write_image(&dir.join(format!("{:06}.{}", number, ext)), &frame, format)
+        }
+    }
+}
+
+fn write_image(path: &Path, frame: &Grid<Rgb>, format: Format) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut out = BufWriter::new(File::create(path)?);
+    match format {
+        Format::Ppm => write_ppm(&mut out, frame)?,
+        _ => write_png(&mut out, frame)?,
+    }
+    out.flush()
+}
+
+// the width x height window at (x, y), black where it goes past the frame
+fn fit(frame: &Grid<Rgb>, x: usize, y: usize, width: usize, height: usize) -> Grid<Rgb> {
+    if (x, y, width, height) == (0, 0, frame.width(), frame.height()) {
+        return frame.clone();
+    }
+    Grid::from_fn(width, height, |(i, j)| {
+        let pos = y.checked_add(i).zip(x.checked_add(j));
+        pos.and_then(|pos| frame.get(pos))
+            .copied()
+            .unwrap_or_default()
+    })
+}
+
+fn scale(frame: &Grid<Rgb>, factor: usize) -> io::Result<Grid<Rgb>> {
+    if factor == 1 {
+        return Ok(frame.clone());
+    }
+    let (width, height) = (frame.width(), frame.height());
+    if pixels(width, height, factor).is_none() {
+        return Err(io::Error::other(format!(
+            "frame of {}x{} cells too large at scale {}",
+            width, height, factor
+        )));
+    }
+    Ok(Grid::from_fn(width * factor, height * factor, |(i, j)| {
+        frame[(i / factor, j / factor)]
+    }))
+}
+
+fn write_ppm<W: Write>(out: &mut W, frame: &Grid<Rgb>) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", frame.width(), frame.height())?;
+    for pixel in frame.iter() {
+        out.write_all(pixel)?;
+    }
+    Ok(())
+}
+
+// truecolour, 8 bits per channel
+fn write_png<W: Write>(out: &mut W, frame: &Grid<Rgb>) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(out, frame.width() as u32, frame.height() as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    let data: Vec<u8> = frame.iter().flatten().copied().collect();
+    writer.write_image_data(&data).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+// animated, looping forever, every frame with its own colour table
+struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+}
+
+fn gif_size(n: usize) -> io::Result<u16> {
+    u16::try_from(n).map_err(|_| io::Error::other("frame too large for a gif"))
+}
+
+// exact up to 256 colours, quantized beyond
+fn gif_frame(frame: &Grid<Rgb>) -> io::Result<gif::Frame<'static>> {
+    let (width, height) = (gif_size(frame.width())?, gif_size(frame.height())?);
+    let pixels: Vec<u8> = frame.iter().flatten().copied().collect();
+    let mut frame = gif::Frame::from_rgb(width, height, &pixels);
+    frame.delay = GIF_DELAY;
+    Ok(frame)
+}
+
+impl<W: Write> GifWriter<W> {
+    fn new(out: W, width: usize, height: usize) -> io::Result<Self> {
+        let (width, height) = (gif_size(width)?, gif_size(height)?);
+        let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            encoder,
+            width,
+            height,
+        })
+    }
+
+    fn frame(&mut self, frame: &gif::Frame) -> io::Result<()> {
+        assert_eq!((frame.width, frame.height), (self.width, self.height));
+        self.encoder.write_frame(frame).map_err(io::Error::other)
+    }
+
+    fn finish(self) -> io::Result<W> {
+        self.encoder.into_inner().map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoders() {
+        let frame = Grid::from_fn(3, 2, |(i, j)| [j as u8 * 100, i as u8 * 255, 7]);
+        let pixels: Vec<u8> = frame.iter().flatten().copied().collect();
+
+        let mut ppm = Vec::new();
+        write_ppm(
+            &mut ppm,
+            &Grid::from_fn(2, 1, |(_, j)| [j as u8 * 255, 0, 0]),
+        )
+        .unwrap();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x00\x00\x00\xff\x00\x00");
+
+        let mut png = Vec::new();
+        write_png(&mut png, &frame).unwrap();
+        let mut reader = png::Decoder::new(io::Cursor::new(png)).read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut decoded).unwrap();
+        assert_eq!(
+            (info.width, info.height, info.color_type),
+            (3, 2, png::ColorType::Rgb)
+        );
+        assert_eq!(decoded, pixels);
+
+        let mut gif = GifWriter::new(Vec::new(), 3, 2).unwrap();
+        gif.frame(&gif_frame(&frame).unwrap()).unwrap();
+        gif.frame(&gif_frame(&frame.map(|&[r, g, b]| [b, g, r])).unwrap())
+            .unwrap();
+        let gif = gif.finish().unwrap();
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(io::Cursor::new(gif)).unwrap();
+        assert_eq!(decoder.repeat(), gif::Repeat::Infinite);
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height, frame.delay), (3, 2, GIF_DELAY));
+            let rgb: Vec<u8> = frame
+                .buffer
+                .chunks(4)
+                .flat_map(|p| p[..3].to_vec())
+                .collect();
+            frames.push(rgb);
+        }
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], pixels);
+    }
+
+    #[test]
+    fn fit_and_scale() {
+        let frame = Grid::from_fn(2, 2, |(i, j)| [i as u8, j as u8, 0]);
+        let window = fit(&frame, 1, 0, 2, 1);
+        assert_eq!(
+            window.iter().copied().collect::<Vec<_>>(),
+            vec![[0, 1, 0], [0, 0, 0]]
+        );
+        let scaled = scale(&frame, 2).unwrap();
+        assert_eq!((scaled.width(), scaled.height()), (4, 4));
+        assert_eq!(scaled[(3, 1)], [1, 0, 0]);
+
+        assert_eq!(
+            "1,2,3,4".parse(),
+            Ok(Crop {
+                x: 1,
+                y: 2,
+                width: 3,
+                height: 4
+            })
+        );
+        assert!("1,2,0,4".parse::<Crop>().is_err());
+    }
+
+    #[test]
+    fn too_large() {
+        let frame = Grid::from_fn(2, 2, |(i, j)| [i as u8, j as u8, 0]);
+        // past the end of the numbers
+        let window = fit(&frame, usize::MAX, 1, 1, 2);
+        assert_eq!(window.iter().copied().collect::<Vec<_>>(), vec![[0; 3]; 2]);
+        assert!(scale(&frame, usize::MAX / 2).is_err());
+        assert!(scale(&frame, 1 << 13).is_err());
+        assert!(format!("{},0,2,1", usize::MAX).parse::<Crop>().is_err());
+
+        let options = |crop: Option<&str>, scale| Options {
+            crop: crop.map(|crop| crop.parse().unwrap()),
+            scale,
+            ..Options::new(PathBuf::new())
+        };
+        assert_eq!(options(Some("5,5,100,100"), 8).check(), Ok(()));
+        assert!(options(None, 0).check().is_err());
+        assert!(options(None, 1 << 14).check().is_err());
+        assert!(options(Some("0,0,100000,100000"), 1).check().is_err());
+        assert!(options(Some("0,0,10000,10000"), 1 << 8).check().is_err());
+    }
+}
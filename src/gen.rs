@@ -0,0 +1,148 @@
+//! Random puzzle inputs, to stress-test and benchmark the solutions.
+//!
+//! Every day implements [`Generator`], producing a valid input from a seeded
+//! [`Rng`] and a size, whose meaning depends on the puzzle: the number of
+//! lines, the side of a map and so on. The same seed and size always give the
+//! same input.
+//!
+//! ```
+//! let input = adventofcode_2022::gen::generate(16, 42, Some(20)).unwrap();
+//! assert!(input.starts_with("Valve "));
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// A small pseudo-random generator (splitmix64), good enough for inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in the given inclusive range.
+    pub fn range(&mut self, range: RangeInclusive<isize>) -> isize {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        let span = hi.abs_diff(lo) as u64 + 1;
+        lo.wrapping_add((self.next_u64() % span) as isize)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A random lowercase word of the given length.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+/// Random inputs for a puzzle.
+pub trait Generator {
+    /// The size used when none is given, close to the real inputs.
+    const SIZE: usize;
+
+    /// A random input of about the given size, which the solution accepts.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generates an input for `day`, or `None` if there is no such day.
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Option<String> {
+    fn run<G: Generator>(seed: u64, size: Option<usize>) -> String {
+        G::generate(&mut Rng::new(seed), size.unwrap_or(G::SIZE))
+    }
+
+    let input = match day {
+        1 => run::<day1::Day1>(seed, size),
+        2 => run::<day2::Day2>(seed, size),
+        3 => run::<day3::Day3>(seed, size),
+        4 => run::<day4::Day4>(seed, size),
+        5 => run::<day5::Day5>(seed, size),
+        6 => run::<day6::Day6>(seed, size),
+        7 => run::<day7::Day7>(seed, size),
+        8 => run::<day8::Day8>(seed, size),
+        9 => run::<day9::Day9>(seed, size),
+        10 => run::<day10::Day10>(seed, size),
+        11 => run::<day11::Day11>(seed, size),
+        12 => run::<day12::Day12>(seed, size),
+        13 => run::<day13::Day13>(seed, size),
+        14 => run::<day14::Day14>(seed, size),
+        15 => run::<day15::Day15>(seed, size),
+        16 => run::<day16::Day16>(seed, size),
+        17 => run::<day17::Day17>(seed, size),
+        18 => run::<day18::Day18>(seed, size),
+        19 => run::<day19::Day19>(seed, size),
+        20 => run::<day20::Day20>(seed, size),
+        21 => run::<day21::Day21>(seed, size),
+        22 => run::<day22::Day22>(seed, size),
+        23 => run::<day23::Day23>(seed, size),
+        24 => run::<day24::Day24>(seed, size),
+        25 => run::<day25::Day25>(seed, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng() {
+        let mut rng = Rng::new(7);
+        let mut again = Rng::new(7);
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u64(), again.next_u64());
+            let (n, m) = (rng.below(10), rng.range(-3..=3));
+            assert!(n < 10 && (-3..=3).contains(&m));
+            assert_eq!((n, m), (again.below(10), again.range(-3..=3)));
+        }
+    }
+
+    // small inputs of every day can be solved
+    #[test]
+    fn solvable() {
+        for day in 1..=DAYS {
+            for seed in 0..3 {
+                let input = generate(day, seed, Some(12)).unwrap();
+                let solve = solver(day).unwrap();
+                if let Err(e) = solve(&input, &Part::BOTH) {
+                    panic!("seed {}: {}\n{}", seed, e, input);
+                }
+            }
+        }
+    }
+}
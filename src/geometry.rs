@@ -0,0 +1,408 @@
+//! Points and directions on the plane and in space.
+//!
+//! [`Point2`] uses the same convention as the [grid](crate::grid): `i` is the
+//! row, growing downwards (south), and `j` the column, growing rightwards
+//! (east).
+//!
+//! ```
+//! use adventofcode_2022::geometry::{Direction, Point2};
+//!
+//! let p = Point2::new(2, 3) + Direction::N;
+//! assert_eq!(p, Point2::new(1, 3));
+//! assert_eq!(p.manhattan(Point2::ORIGIN), 4);
+//! ```
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A number usable as a coordinate.
+pub trait Coord: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Distance from another coordinate, always positive.
+    fn distance(self, other: Self) -> usize;
+
+    /// `-1`, `0` or `1` for signed numbers, `0` or `1` for unsigned ones.
+    fn signum(self) -> Self;
+
+    /// Adds a signed offset, `None` if the result is out of range.
+    fn checked_offset(self, delta: isize) -> Option<Self>;
+}
+
+macro_rules! signed_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> usize {
+                self.abs_diff(other) as usize
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                self.checked_add(delta.try_into().ok()?)
+            }
+        }
+    )*};
+}
+
+macro_rules! unsigned_coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> usize {
+                self.abs_diff(other) as usize
+            }
+
+            fn signum(self) -> Self {
+                self.min(1)
+            }
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                (self as isize).checked_add(delta)?.try_into().ok()
+            }
+        }
+    )*};
+}
+
+signed_coord!(i32, i64, isize);
+unsigned_coord!(u32, usize);
+
+/// One of the four cardinal directions, numbered clockwise from east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    E = 0,
+    S = 1,
+    W = 2,
+    N = 3,
+}
+
+impl Direction {
+    /// All directions, clockwise from east.
+    pub const ALL: [Self; 4] = [Self::E, Self::S, Self::W, Self::N];
+
+    /// `(di, dj)` of a step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::E => (0, 1),
+            Self::S => (1, 0),
+            Self::W => (0, -1),
+            Self::N => (-1, 0),
+        }
+    }
+
+    pub fn from_offset(offset: (isize, isize)) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.offset() == offset)
+    }
+
+    pub fn rotate_clock(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_anticlock(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// `>`, `v`, `<` or `^`.
+    pub fn arrow(self) -> char {
+        match self {
+            Self::E => '>',
+            Self::S => 'v',
+            Self::W => '<',
+            Self::N => '^',
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// A point on the plane: row `i` and column `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2<T = isize> {
+    pub i: T,
+    pub j: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(i: T, j: T) -> Self {
+        Self { i, j }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.i.distance(other.i) + self.j.distance(other.j)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.i.distance(other.i).max(self.j.distance(other.j))
+    }
+
+    /// Each coordinate reduced to its sign: a unit step towards the point.
+    pub fn signum(self) -> Self {
+        Self::new(self.i.signum(), self.j.signum())
+    }
+
+    /// The point moved by `(di, dj)`, if it is in range.
+    pub fn offset(self, (di, dj): (isize, isize)) -> Option<Self> {
+        Some(Self::new(
+            self.i.checked_offset(di)?,
+            self.j.checked_offset(dj)?,
+        ))
+    }
+
+    /// The point one step away in the given direction, if it is in range.
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        self.offset(dir.offset())
+    }
+
+    /// The 4 orthogonal neighbours that are in range.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(dir))
+    }
+
+    /// The 8 neighbours, diagonals included, that are in range.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|di| (-1..=1).map(move |dj| (di, dj)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |offset| self.offset(offset))
+    }
+}
+
+// also to and from grid positions
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((i, j): (T, T)) -> Self {
+        Self::new(i, j)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.i, p.j)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.i, self.j)
+    }
+}
+
+/// A point in space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The 6 neighbours sharing a face, that are in range.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        const SIDES: [(isize, isize, isize); 6] = [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ];
+        SIDES.into_iter().filter_map(move |(dx, dy, dz)| {
+            Some(Self::new(
+                self.x.checked_offset(dx)?,
+                self.y.checked_offset(dy)?,
+                self.z.checked_offset(dz)?,
+            ))
+        })
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// the componentwise operations, and scaling, of both kinds of points
+macro_rules! point_ops {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c + rhs.$c),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c - rhs.$c),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self { $($c: -self.$c),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self::Output {
+                Self { $($c: self.$c * rhs),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$c += rhs.$c;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$c -= rhs.$c;)*
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$c *= rhs;)*
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { i, j });
+point_ops!(Point3 { x, y, z });
+
+/// A step in a direction, which panics if it leaves the range of `T`, as
+/// other arithmetic does. [`Point2::step`] checks instead.
+impl<T: Coord> Add<Direction> for Point2<T> {
+    type Output = Self;
+    fn add(self, dir: Direction) -> Self::Output {
+        self.step(dir).expect("point out of range")
+    }
+}
+
+impl<T: Coord> AddAssign<Direction> for Point2<T> {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let (p, q) = (Point2::new(1, -2), Point2::new(4, 2));
+        assert_eq!(p + q, Point2::new(5, 0));
+        assert_eq!(q - p, Point2::new(3, 4));
+        assert_eq!(-p * 3, Point2::new(-3, 6));
+        assert_eq!((q - p).signum(), Point2::new(1, 1));
+        assert_eq!(p.manhattan(q), 7);
+        assert_eq!(p.chebyshev(q), 4);
+
+        let mut r = Point3::new(1, 2, 3);
+        r += Point3::new(1, 1, 1);
+        r *= 2;
+        r -= Point3::ORIGIN;
+        assert_eq!(r, Point3::new(4, 6, 8));
+        assert_eq!(r.manhattan(Point3::ORIGIN), 18);
+        assert_eq!(r.chebyshev(Point3::new(0, 0, 10)), 6);
+    }
+
+    #[test]
+    fn neighbours() {
+        let p: Point2<i32> = Point2::new(5, 5);
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p.neighbours8().all(|q| p.chebyshev(q) == 1));
+        assert_eq!(p.neighbours8().count(), 8);
+        // out of range ones are left out
+        let corner: Point2<usize> = Point2::ORIGIN;
+        assert_eq!(
+            corner.neighbours4().collect::<Vec<_>>(),
+            [Point2::new(0, 1), Point2::new(1, 0)]
+        );
+        assert_eq!(corner.neighbours8().count(), 3);
+        assert_eq!(Point3::new(0, 0, 0).neighbours6().count(), 6);
+        assert_eq!(Point3::new(0_usize, 1, 1).neighbours6().count(), 5);
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.rotate_clock().rotate_anticlock(), dir);
+            assert_eq!(dir.rotate_clock().rotate_clock(), dir.reverse());
+            assert_eq!(Direction::from_offset(dir.offset()), Some(dir));
+            assert_eq!(
+                Point2::ORIGIN + dir + dir.reverse(),
+                Point2::<isize>::ORIGIN
+            );
+        }
+        assert_eq!(Direction::E.rotate_clock(), Direction::S);
+        assert_eq!(Direction::from_offset((1, 1)), None);
+        assert_eq!(Point2::new(1_usize, 0).step(Direction::W), None);
+        assert_eq!(Point2::new(1_usize, 0) + Direction::N, Point2::new(0, 0));
+    }
+}
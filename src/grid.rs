@@ -0,0 +1,317 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut, Range},
+};
+
+use crate::{parse, ParseError};
+
+/// Position of a cell: `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: up, down, left, right.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of all 8 neighbours, diagonals included.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells sized at runtime, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds each cell from its position.
+    pub fn from_fn<F: FnMut(Pos) -> T>(width: usize, height: usize, mut cell: F) -> Self {
+        let cells = (0..width * height)
+            .map(|k| cell((k / width, k % width)))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, every line must have the same length.
+    pub fn parse<F>(day: usize, input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in parse::lines(day, input) {
+            let len = line.text.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => {
+                    return Err(line.error(line.text, format!("expected {} columns", w)));
+                }
+                _ => (),
+            }
+            for (j, c) in line.text.char_indices() {
+                let c = cell(c).ok_or_else(|| {
+                    line.error(&line.text[j..j + c.len_utf8()], "unexpected char")
+                })?;
+                cells.push(c);
+            }
+            height += 1;
+        }
+        match width {
+            None | Some(0) => Err(ParseError::input(day, "empty grid")),
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `pos` by `(di, dj)`, if it stays within the grid.
+    pub fn offset(&self, (i, j): Pos, (di, dj): (isize, isize)) -> Option<Pos> {
+        let i = i.checked_add_signed(di)?;
+        let j = j.checked_add_signed(dj)?;
+        if self.contains((i, j)) {
+            Some((i, j))
+        } else {
+            None
+        }
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height * width).map(move |k| (k / width, k % width))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(
+            i < self.height,
+            "row {} outside of {}x{} grid",
+            i,
+            self.width,
+            self.height
+        );
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            j < self.width,
+            "column {} outside of {}x{} grid",
+            j,
+            self.width,
+            self.height
+        );
+        self.cells[j..].iter().step_by(self.width.max(1))
+    }
+
+    /// Positions of row `i`, left to right.
+    pub fn row_positions(&self, i: usize) -> impl DoubleEndedIterator<Item = Pos> + Clone {
+        (0..self.width).map(move |j| (i, j))
+    }
+
+    /// Positions of column `j`, top to bottom.
+    pub fn col_positions(&self, j: usize) -> impl DoubleEndedIterator<Item = Pos> + Clone {
+        (0..self.height).map(move |i| (i, j))
+    }
+
+    /// The orthogonal neighbours of `pos` within the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// All neighbours of `pos` within the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// Positions from `pos` (excluded) in direction `dir` up to the edge.
+    pub fn ray(&self, pos: Pos, dir: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        let mut cur = pos;
+        std::iter::from_fn(move || {
+            cur = self.offset(cur, dir)?;
+            Some(cur)
+        })
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grows or shrinks the grid at the bottom to `height` rows.
+    pub fn resize_rows(&mut self, height: usize, fill: T)
+    where
+        T: Clone,
+    {
+        self.cells.resize(height * self.width, fill);
+        self.height = height;
+    }
+
+    /// Removes a range of rows, the following ones move up.
+    pub fn drain_rows(&mut self, rows: Range<usize>) {
+        self.cells
+            .drain(rows.start * self.width..rows.end * self.width);
+        self.height -= rows.len();
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {:?} outside of {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {:?} outside of {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse(0, "123\n456", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = Grid::parse(3, "123\n45", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.day, err.line), (3, 2));
+        let err = Grid::parse(3, "123\n4x6", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn iterators() {
+        let grid = Grid::from_fn(3, 3, |(i, j)| 3 * i + j);
+        assert_eq!(grid.row(1), &[3, 4, 5]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![2, 5, 8]);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.ray((2, 0), (-1, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (0, 2)]
+        );
+        assert_eq!(grid.col_positions(1).next_back(), Some((2, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 outside of 3x2 grid")]
+    fn column_out_of_bounds() {
+        let _ = Grid::new(3, 2, 0).col(3);
+    }
+
+    #[test]
+    fn resize() {
+        let mut grid = Grid::new(2, 1, 0);
+        grid.resize_rows(3, 1);
+        grid[(2, 1)] = 2;
+        grid.drain_rows(0..1);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 1], &[1, 2]]);
+    }
+}
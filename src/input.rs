@@ -0,0 +1,88 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory with the `dayN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory holding the puzzle inputs: `$AOC_INPUT_DIR`, or `input/` in the
+/// crate root.
+pub fn input_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `dayN.txt` in the [input directory](input_dir).
+    Day(usize),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interprets a command line argument: a file path, `-` for stdin, or
+    /// nothing for the default input of `day`.
+    pub fn from_arg(day: usize, arg: Option<&str>) -> Self {
+        match arg {
+            None => Self::Day(day),
+            Some("-") => Self::Stdin,
+            Some(path) => Self::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let res = match self {
+            Self::Day(day) => fs::read_to_string(input_dir().join(format!("day{}.txt", day))),
+            Self::Path(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        res.map_err(|error| InputError {
+            input: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day(day) => write!(
+                f,
+                "{}",
+                input_dir().join(format!("day{}.txt", day)).display()
+            ),
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// A puzzle input that could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub input: Source,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot read input {}: {}", self.input, self.error)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,94 @@
+//! Solutions to the Advent of Code 2022 puzzles.
+//!
+//! Each day lives in its own module and implements [`Solution`]:
+//!
+//! ```no_run
+//! use adventofcode_2022::{day16::Day16, input::Source, Solution};
+//!
+//! let input = Source::Day(16).read().unwrap();
+//! let (part1, part2) = Day16::solve(&input)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod answers;
+pub mod client;
+pub mod cycle;
+pub mod frames;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod render;
+pub mod scaffold;
+pub mod search;
+mod solution;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Part, Run, Solution};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Number of days in the calendar, raised by `aoc new` for the days after it.
+pub const DAYS: usize = 25;
+
+/// Parses a puzzle input and computes the answers to the given parts.
+pub type Solver = fn(&str, &[Part]) -> Result<Run, ParseError>;
+
+/// Returns the solver for the given day, if there is one.
+pub fn solver(day: usize) -> Option<Solver> {
+    let solve: Solver = match day {
+        1 => day1::Day1::run,
+        2 => day2::Day2::run,
+        3 => day3::Day3::run,
+        4 => day4::Day4::run,
+        5 => day5::Day5::run,
+        6 => day6::Day6::run,
+        7 => day7::Day7::run,
+        8 => day8::Day8::run,
+        9 => day9::Day9::run,
+        10 => day10::Day10::run,
+        11 => day11::Day11::run,
+        12 => day12::Day12::run,
+        13 => day13::Day13::run,
+        14 => day14::Day14::run,
+        15 => day15::Day15::run,
+        16 => day16::Day16::run,
+        17 => day17::Day17::run,
+        18 => day18::Day18::run,
+        19 => day19::Day19::run,
+        20 => day20::Day20::run,
+        21 => day21::Day21::run,
+        22 => day22::Day22::run,
+        23 => day23::Day23::run,
+        24 => day24::Day24::run,
+        25 => day25::Day25::run,
+        _ => return None,
+    };
+    Some(solve)
+}
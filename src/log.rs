@@ -0,0 +1,199 @@
+//! Leveled logging to standard error, filtered per day.
+//!
+//! Messages are tagged with the module they come from (`day19`), and shown if
+//! their level is enabled for it. The filter is a comma separated list of
+//! `level` (for every module) and `module=level` entries, e.g. `debug` or
+//! `warn,day19=trace`. Nothing below [`Level::Warn`] is shown by default.
+//!
+//! ```
+//! adventofcode_2022::log::init("day19=trace").unwrap();
+//! adventofcode_2022::trace!("expanding {}", 42);
+//! ```
+
+use std::{
+    fmt::{Arguments, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level enabled by passing `-v` the given number of times.
+    pub fn from_verbosity(count: usize) -> Self {
+        match count {
+            0 => Self::Warn,
+            1 => Self::Info,
+            2 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        };
+        f.pad(s)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "warn" => Ok(Self::Warn),
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("unknown log level '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Filter {
+    default: Level,
+    modules: Vec<(String, Level)>,
+}
+
+impl Filter {
+    fn level(&self, module: &str) -> Level {
+        self.modules
+            .iter()
+            .rev()
+            .find(|(name, _)| name == module)
+            .map_or(self.default, |&(_, level)| level)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter {
+            default: Level::Warn,
+            modules: Vec::new(),
+        };
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((module, level)) => filter.modules.push((module.to_string(), level.parse()?)),
+                None => filter.default = entry.parse()?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+// the most verbose level enabled for any module, to skip most checks quickly
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Sets the filter for the rest of the program, can only be done once.
+pub fn init(filter: &str) -> Result<(), String> {
+    let filter: Filter = filter.parse()?;
+    let max = filter
+        .modules
+        .iter()
+        .map(|&(_, level)| level)
+        .fold(filter.default, Level::max);
+    FILTER
+        .set(filter)
+        .map_err(|_| "logging was already initialized".to_string())?;
+    MAX_LEVEL.store(max as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+// the module of a path such as `adventofcode_2022::day19`
+fn module_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Whether messages at `level` from the module at `path` are shown.
+pub fn enabled(level: Level, path: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let allowed = FILTER
+        .get()
+        .map_or(Level::Warn, |filter| filter.level(module_name(path)));
+    level <= allowed
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, path: &str, args: Arguments) {
+    eprintln!("[{} {:<5}] {}", module_name(path), level, args);
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs at [`Level::Error`](crate::log::Level::Error).
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+/// Logs at [`Level::Warn`](crate::log::Level::Warn).
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+/// Logs at [`Level::Info`](crate::log::Level::Info).
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Logs at [`Level::Debug`](crate::log::Level::Debug).
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Logs at [`Level::Trace`](crate::log::Level::Trace).
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter() {
+        let filter: Filter = "info, day19=trace,day16=error".parse().unwrap();
+        assert_eq!(filter.level("day19"), Level::Trace);
+        assert_eq!(filter.level("day16"), Level::Error);
+        assert_eq!(filter.level("day1"), Level::Info);
+        assert_eq!(module_name("adventofcode_2022::day19"), "day19");
+
+        assert!("day19=loud".parse::<Filter>().is_err());
+    }
+}
@@ -1,3 +1,714 @@
+use std::{
+    env,
+    fmt::Display,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use adventofcode_2022::{
+    answers::{self, Answers},
+    client::{Client, Config, Fetched, Submitted, Verdict},
+    frames, gen,
+    input::{input_dir, Source},
+    log, render, scaffold, solver, Answer, Part, Run, DAYS,
+};
+
+const USAGE: &str = "\
+usage: aoc [-v...] [<debug options>] run <day> [<options>] [<input>]
+       aoc [-v...] [<debug options>] run --all [--jobs <n>] [<options>]
+       aoc [-v...] [<debug options>] verify
+       aoc gen <day> [--seed <n>] [--size <n>]
+       aoc fetch <day>
+       aoc submit <day> <part>
+       aoc new <day>
+
+<input> is a file path, or - to read standard input. By default the input of
+day N is read from dayN.txt in $AOC_INPUT_DIR, or input/ in the crate root.
+
+options:
+  --jobs <n>            with --all, solve n days at a time, then print a
+                        summary of the answers and times, slowest first
+  --part <1|2>          compute only one part
+  --timings             print how long parsing and each part took
+  --format <text|json>  with json, print one object per line for each answer,
+                        with the seconds spent solving that part:
+                        {\"day\": 1, \"part\": 1, \"answer\": 70613, \"elapsed\": 0.000016}
+  --frames <dir>        capture the steps of the simulations of days 14, 17, 23
+                        and 24 as images in <dir>, one sequence for each part
+  --frame-format <ppm|png|gif>
+                        numbered images, or an animation (default: png)
+  --frame-skip <n>      capture one step every n
+  --crop <x,y,w,h>      keep only a part of each frame, in cells
+  --scale <n>           draw each cell as n by n pixels
+
+Debug output of the solutions goes to standard error: -v shows what they are
+doing, -vv and -vvv even more. $AOC_LOG selects the level per day, e.g.
+AOC_LOG=day19=trace or AOC_LOG=warn,day16=debug.
+
+debug options:
+  --no-color            draw the grids of the debug output without colours,
+                        as when $NO_COLOR is set or it is not a terminal
+  --view <x,y,w,h>      show only a part of each grid, in cells
+
+verify runs every day and checks the answers against answers.txt in the same
+directory.
+
+gen prints a random input for a day, the same for the same seed (default: 0).
+What the size is depends on the day, e.g. the number of valves for day 16; by
+default it is close to the real inputs.
+
+fetch downloads the input of a day to the input directory, unless it is there
+already. It needs the session cookie of the website in $AOC_SESSION, or in
+.session in the input directory. $AOC_BASE_URL replaces the address of the
+website, and $AOC_MIN_DELAY the seconds to wait between two requests (5).
+
+submit solves a part of a day, fetching its input if needed, and sends the
+answer to the website. The answers it checked are recorded in submissions.txt
+in the input directory, and never sent again, nor are those after a correct
+one.
+
+new starts the solution of a day: src/dayN.rs from templates/day.rs, added
+to the runner and the generators, and an empty input file. The day can be the
+one after the last.";
+
+enum Days {
+    One(usize, Source),
+    All,
+}
+
+#[derive(PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct RunOptions {
+    jobs: Option<usize>,
+    parts: Vec<Part>,
+    timings: bool,
+    format: Format,
+    frames: Option<frames::Options>,
+}
+
+enum Command {
+    Run(Days, RunOptions),
+    Verify,
+    Gen {
+        day: usize,
+        seed: u64,
+        size: Option<usize>,
+    },
+    Fetch(usize),
+    Submit(usize, Part),
+    New(usize),
+}
+
+// number of v's in -v, -vv, ...
+fn verbose_flag(arg: &str) -> Option<usize> {
+    let vs = arg.strip_prefix('-')?;
+    (!vs.is_empty() && vs.chars().all(|c| c == 'v')).then_some(vs.len())
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.peekable();
+
+    let mut verbosity = 0;
+    while let Some(count) = args.peek().and_then(|arg| verbose_flag(arg)) {
+        verbosity += count;
+        args.next();
+    }
+    let mut filter = env::var("AOC_LOG").unwrap_or_default();
+    if verbosity > 0 {
+        filter = format!("{},{}", filter, log::Level::from_verbosity(verbosity));
+    }
+    log::init(&filter).map_err(|e| format!("AOC_LOG: {}", e))?;
+
+    let mut render_options = render::Options::from_env();
+    while let Some(flag) = args.next_if(|arg| arg == "--no-color" || arg == "--view") {
+        if flag == "--no-color" {
+            render_options.color = false;
+        } else {
+            render_options.viewport = Some(flag_value(&mut args, &flag)?);
+        }
+    }
+    render::init(render_options)?;
+
+    parse_command(args)
+}
+
+fn parse_command<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => {
+            let (days, options) = parse_run_args(args)?;
+            Ok(Command::Run(days, options))
+        }
+        Some("verify") => match args.next() {
+            Some(arg) => Err(format!("unexpected argument '{}'", arg)),
+            None => Ok(Command::Verify),
+        },
+        Some("gen") => parse_gen_args(args),
+        Some("submit") => match (args.next(), args.next(), args.next()) {
+            (Some(day), Some(part), None) => {
+                let part = part.parse().map_err(|e| format!("part: {}", e))?;
+                Ok(Command::Submit(parse_day(&day, DAYS)?, part))
+            }
+            (_, _, Some(arg)) => Err(format!("unexpected argument '{}'", arg)),
+            (_, _, None) => Err("missing day or part".to_string()),
+        },
+        Some(cmd @ ("fetch" | "new")) => match (args.next(), args.next()) {
+            (Some(day), None) if cmd == "fetch" => Ok(Command::Fetch(parse_day(&day, DAYS)?)),
+            // the day after the last one too
+            (Some(day), None) => Ok(Command::New(parse_day(&day, DAYS + 1)?)),
+            (None, _) => Err("missing day".to_string()),
+            (_, Some(arg)) => Err(format!("unexpected argument '{}'", arg)),
+        },
+        Some(cmd) => Err(format!("unknown command '{}'", cmd)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_day(arg: &str, last: usize) -> Result<usize, String> {
+    let day: usize = arg
+        .parse()
+        .map_err(|_| format!("unexpected argument '{}'", arg))?;
+    if !(1..=last).contains(&day) {
+        return Err(format!("day must be between 1 and {}", last));
+    }
+    Ok(day)
+}
+
+// the value following a flag
+fn flag_value<T, I>(args: &mut I, flag: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+    I: Iterator<Item = String>,
+{
+    let value = args.next().ok_or(format!("{}: missing value", flag))?;
+    value.parse().map_err(|e| format!("{}: {}", flag, e))
+}
+
+fn parse_run_args<I: Iterator<Item = String>>(mut args: I) -> Result<(Days, RunOptions), String> {
+    let mut days = None;
+    let mut input = None;
+    let mut options = RunOptions {
+        jobs: None,
+        parts: Part::BOTH.to_vec(),
+        timings: false,
+        format: Format::Text,
+        frames: None,
+    };
+    let mut frame_dir: Option<PathBuf> = None;
+    let mut frame_options = frames::Options::new(PathBuf::new());
+    let mut frame_flag = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days = Some(Days::All),
+            "--jobs" => match flag_value(&mut args, &arg)? {
+                0 => return Err("--jobs: must be positive".to_string()),
+                jobs => options.jobs = Some(jobs),
+            },
+            "--part" => options.parts = vec![flag_value(&mut args, &arg)?],
+            "--frames" => frame_dir = Some(flag_value(&mut args, &arg)?),
+            "--frame-format" | "--frame-skip" | "--crop" | "--scale" => {
+                match arg.as_str() {
+                    "--frame-format" => frame_options.format = flag_value(&mut args, &arg)?,
+                    "--frame-skip" => frame_options.skip = flag_value(&mut args, &arg)?,
+                    "--crop" => frame_options.crop = Some(flag_value(&mut args, &arg)?),
+                    _ => frame_options.scale = flag_value(&mut args, &arg)?,
+                }
+                frame_flag = Some(arg);
+            }
+            "--timings" => options.timings = true,
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(format) => {
+                        return Err(format!("--format: expected text or json, got '{}'", format))
+                    }
+                    None => return Err("--format: missing value".to_string()),
+                };
+            }
+            day if days.is_none() => {
+                let day = parse_day(day, DAYS)?;
+                days = Some(Days::One(day, Source::Day(day)));
+            }
+            path if input.is_none() => input = Some(path.to_string()),
+            arg => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days = match (days, input) {
+        (Some(Days::One(day, _)), input) => Days::One(day, Source::from_arg(day, input.as_deref())),
+        (Some(Days::All), None) => Days::All,
+        (Some(Days::All), Some(_)) => return Err("an input cannot be given with --all".to_string()),
+        (None, _) => return Err("missing day (or --all)".to_string()),
+    };
+    if options.jobs.is_some() && !matches!(days, Days::All) {
+        return Err("--jobs requires --all".to_string());
+    }
+    if options.timings && options.format == Format::Json {
+        return Err("--timings cannot be used with --format json".to_string());
+    }
+    match (frame_dir, frame_flag) {
+        (Some(dir), _) => {
+            frame_options.check()?;
+            options.frames = Some(frames::Options {
+                dir,
+                ..frame_options
+            })
+        }
+        (None, Some(flag)) => return Err(format!("{} requires --frames", flag)),
+        (None, None) => (),
+    }
+    Ok((days, options))
+}
+
+fn parse_gen_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let (mut day, mut seed, mut size) = (None, 0, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = flag_value(&mut args, &arg)?,
+            "--size" => size = Some(flag_value(&mut args, &arg)?),
+            d if day.is_none() => day = Some(parse_day(d, DAYS)?),
+            arg => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    Ok(Command::Gen { day, seed, size })
+}
+
+fn run_day(day: usize, input: &Source, parts: &[Part]) -> Result<Run, String> {
+    let solve = solver(day).ok_or(format!("no solution for day {}", day))?;
+    let input = input.read().map_err(|e| e.to_string())?;
+
+    let run = solve(&input, parts).map_err(|e| e.to_string())?;
+    // only worth an error if it was asked for explicitly
+    if let [(part, Answer::None, _)] = run.parts[..] {
+        return Err(format!("day {} has no part {}", day, part));
+    }
+    for (part, answer, _) in &run.parts {
+        if let Answer::Unsolved(reason) = answer {
+            return Err(format!("day {} part {}: {}", day, part, reason));
+        }
+    }
+    Ok(run)
+}
+
+// runs every day on a pool of threads, returns the results in order of day
+// with the time each of them took
+fn run_all(jobs: usize, parts: &[Part]) -> Vec<(usize, Result<Run, String>, Duration)> {
+    let next_day = AtomicUsize::new(1);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(DAYS) {
+            scope.spawn(|| loop {
+                let day = next_day.fetch_add(1, Ordering::Relaxed);
+                if day > DAYS {
+                    break;
+                }
+                let start = Instant::now();
+                let result = run_day(day, &Source::Day(day), parts);
+                let elapsed = start.elapsed();
+                results.lock().unwrap().push((day, result, elapsed));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(day, _, _)| day);
+    results
+}
+
+fn print_answers(day: usize, run: &Run, format: &Format) {
+    for (part, answer, elapsed) in &run.parts {
+        match (answer, format) {
+            (Answer::None, _) => (),
+            (answer, Format::Text) => println!("{}", answer),
+            (answer, Format::Json) => {
+                let answer = match answer {
+                    Answer::Number(n) => n.to_string(),
+                    answer => json_string(&answer.to_string()),
+                };
+                println!(
+                    "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {:.6}}}",
+                    day,
+                    part,
+                    answer,
+                    elapsed.as_secs_f64()
+                );
+            }
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => format!("{:.2?}", time),
+        None => "-".to_string(),
+    }
+}
+
+// prints how long each phase took for every day that ran, and the overall sum
+fn print_timings(runs: &[(usize, Run)]) {
+    let row = |day: &str, parse, part1, part2, total| {
+        println!(
+            "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+            day,
+            format_time(parse),
+            format_time(part1),
+            format_time(part2),
+            format_time(total)
+        );
+    };
+
+    println!();
+    println!(
+        "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+    for (day, run) in runs {
+        row(
+            &day.to_string(),
+            Some(run.parse_time),
+            run.part_time(Part::One),
+            run.part_time(Part::Two),
+            Some(run.total_time()),
+        );
+    }
+    let sum =
+        |time: fn(&Run) -> Option<Duration>| runs.iter().filter_map(|(_, run)| time(run)).sum();
+    row(
+        "total",
+        Some(sum(|run| Some(run.parse_time))),
+        Some(sum(|run| run.part_time(Part::One))),
+        Some(sum(|run| run.part_time(Part::Two))),
+        Some(sum(|run| Some(run.total_time()))),
+    );
+}
+
+// prints the answers of every day and how long it took, slowest first, then
+// the sum of the times and how long the whole run took
+fn print_summary(
+    results: &[(usize, Result<Run, String>, Duration)],
+    elapsed: Duration,
+    jobs: usize,
+) {
+    let mut rows: Vec<_> = results.iter().collect();
+    rows.sort_by_key(|&&(day, _, time)| (std::cmp::Reverse(time), day));
+
+    println!();
+    println!("{:>5}  {:>10}  answers", "day", "time");
+    for (day, result, time) in rows {
+        let answers = match result {
+            Ok(run) => run
+                .answers()
+                .map(|answer| answers::escape(&answer.to_string()))
+                .collect::<Vec<_>>()
+                .join("  "),
+            Err(_) => "error".to_string(),
+        };
+        println!("{:>5}  {:>10}  {}", day, format_time(Some(*time)), answers);
+    }
+    let total: Duration = results.iter().map(|&(_, _, time)| time).sum();
+    println!("{:>5}  {:>10}", "total", format_time(Some(total)));
+    println!();
+    println!(
+        "{} days in {} with {} jobs",
+        results.len(),
+        format_time(Some(elapsed)),
+        jobs
+    );
+}
+
+// solves a part and submits the answer, returns whether it was right
+fn submit(day: usize, part: Part) -> Result<bool, String> {
+    let client = Client::new(Config::from_env().map_err(|e| e.to_string())?);
+    client.fetch(day).map_err(|e| e.to_string())?;
+    let run = run_day(day, &Source::Day(day), &[part])?;
+    let answer = run.answers().next().expect("no answer").to_string();
+    if answer.contains('\n') {
+        return Err(format!(
+            "the answer spans several lines, read it and submit it by hand:\n{}",
+            answer
+        ));
+    }
+    println!("{}", answer);
+
+    match client
+        .submit(day, part, &answer)
+        .map_err(|e| e.to_string())?
+    {
+        Submitted::Sent(verdict) => {
+            println!("{}", verdict);
+            Ok(verdict == Verdict::Correct)
+        }
+        Submitted::Recorded(previous) if previous.answer == answer => {
+            println!("{} (submitted before)", previous.verdict);
+            Ok(previous.verdict == Verdict::Correct)
+        }
+        Submitted::Recorded(previous) => {
+            println!("not sent, {} was the right answer", previous.answer);
+            Ok(false)
+        }
+    }
+}
+
+// runs every day and prints a table comparing the answers with the expected
+// ones, returns whether they all match
+fn verify() -> Result<bool, String> {
+    let answers = Answers::load().map_err(|e| e.to_string())?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    println!("{:>3}  {:>4}  {:<7}  answer", "day", "part", "status");
+    for day in 1..=DAYS {
+        let results = match (solver(day), Source::Day(day).read()) {
+            (Some(solve), Ok(input)) => match solve(&input, &Part::BOTH) {
+                Ok(run) => run.answers().cloned().map(Ok).collect(),
+                Err(e) => vec![Err(e.to_string()); 2],
+            },
+            (None, _) => vec![Err(format!("no solution for day {}", day)); 2],
+            (_, Err(e)) => vec![Err(e.to_string()); 2],
+        };
+
+        for (part, result) in Part::BOTH.into_iter().zip(results) {
+            let expected = answers.get(day, part);
+            let (status, detail) = match (result, expected) {
+                (Ok(Answer::None), None) => continue,
+                (Ok(Answer::Unsolved(reason)), _) => {
+                    failed += 1;
+                    ("FAIL", reason)
+                }
+                (Ok(answer), Some(expected)) if answer.to_string() == expected => {
+                    passed += 1;
+                    ("PASS", answers::escape(expected))
+                }
+                (Ok(answer), Some(expected)) => {
+                    failed += 1;
+                    let answer = answers::escape(&answer.to_string());
+                    (
+                        "FAIL",
+                        format!("{} (expected {})", answer, answers::escape(expected)),
+                    )
+                }
+                (Ok(answer), None) => {
+                    missing += 1;
+                    ("MISSING", answers::escape(&answer.to_string()))
+                }
+                (Err(e), _) => {
+                    failed += 1;
+                    ("FAIL", e)
+                }
+            };
+            println!("{:>3}  {:>4}  {:<7}  {}", day, part, status, detail);
+        }
+    }
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    Ok(failed == 0)
+}
+
 fn main() {
-    println!("Hello, world!");
+    let (days, options) = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(days, options)) => (days, options),
+        Ok(Command::Gen { day, seed, size }) => {
+            match gen::generate(day, seed, size) {
+                Some(input) => print!("{}", input),
+                None => {
+                    eprintln!("error: no generator for day {}", day);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Ok(Command::Fetch(day)) => {
+            let fetched = Config::from_env().and_then(|config| Client::new(config).fetch(day));
+            match fetched {
+                Ok(Fetched::Downloaded(path)) => println!("downloaded {}", path.display()),
+                Ok(Fetched::Cached(path)) => println!("{} is there already", path.display()),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Ok(Command::New(day)) => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            match scaffold::create(root, &input_dir(), day) {
+                Ok(created) => created
+                    .iter()
+                    .for_each(|path| println!("created {}", path.display())),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Ok(Command::Submit(day, part)) => match submit(day, part) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        },
+        Ok(Command::Verify) => match verify() {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        },
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Some(frame_options) = options.frames.clone() {
+        if let Err(e) = frames::init(frame_options) {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let mut runs = Vec::new();
+    let mut failed = false;
+    match days {
+        Days::One(day, input) => match run_day(day, &input, &options.parts) {
+            Ok(run) => {
+                print_answers(day, &run, &options.format);
+                runs.push((day, run));
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        },
+        Days::All => match options.jobs {
+            None => {
+                for day in 1..=DAYS {
+                    if options.format == Format::Text {
+                        println!("== Day {} ==", day);
+                    }
+                    match run_day(day, &Source::Day(day), &options.parts) {
+                        Ok(run) => {
+                            print_answers(day, &run, &options.format);
+                            runs.push((day, run));
+                        }
+                        Err(e) => {
+                            eprintln!("error: {}", e);
+                            failed = true;
+                        }
+                    }
+                }
+            }
+            Some(jobs) => {
+                let start = Instant::now();
+                let mut results = run_all(jobs, &options.parts);
+                let elapsed = start.elapsed();
+                for (day, result, _) in &results {
+                    if options.format == Format::Text {
+                        println!("== Day {} ==", day);
+                    }
+                    match result {
+                        Ok(run) => print_answers(*day, run, &options.format),
+                        Err(e) => {
+                            eprintln!("error: day {}: {}", day, e);
+                            failed = true;
+                        }
+                    }
+                }
+                if options.format == Format::Text {
+                    print_summary(&results, elapsed, jobs);
+                }
+                for (day, result, _) in results.drain(..) {
+                    if let Ok(run) = result {
+                        runs.push((day, run));
+                    }
+                }
+            }
+        },
+    }
+    if options.timings {
+        print_timings(&runs);
+    }
+    if let Err(e) = frames::finish() {
+        eprintln!("error: cannot write frames: {}", e);
+        failed = true;
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn command(args: &[&str]) -> Result<Command, String> {
+        parse_command(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn new_day() {
+        let day = match command(&["new", &(DAYS + 1).to_string()]) {
+            Ok(Command::New(day)) => day,
+            _ => panic!("not a new command"),
+        };
+        assert!(command(&["new", "0"]).is_err());
+        assert_eq!(
+            command(&["new", &(DAYS + 2).to_string()]).err(),
+            Some(format!("day must be between 1 and {}", DAYS + 1))
+        );
+
+        // in a copy of the runner, where every day before exists already
+        let root = env::temp_dir().join(format!("aoc-new-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        for file in ["lib.rs", "gen.rs", "day1.rs"] {
+            fs::copy(src.join(file), root.join("src").join(file)).unwrap();
+        }
+        assert!(matches!(
+            scaffold::create(&root, &root, 1),
+            Err(scaffold::ScaffoldError::Exists(_))
+        ));
+        scaffold::create(&root, &root, day).unwrap();
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains(&format!("pub mod day{};", day)));
+        assert!(lib.contains(&format!("{} => day{}::Day{}::run,", day, day, day)));
+        assert!(lib.contains(&format!("pub const DAYS: usize = {};", day)));
+        let gen = fs::read_to_string(root.join("src/gen.rs")).unwrap();
+        assert!(gen.contains(&format!("run::<day{}::Day{}>", day, day)));
+        fs::remove_dir_all(&root).unwrap();
+    }
 }
@@ -0,0 +1,494 @@
+use std::{error::Error, fmt::Display, str::FromStr, vec};
+
+/// A malformed puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day of the puzzle, 0 if not known yet.
+    pub day: usize,
+    /// 1-based line number, 0 if the error is not about a single line.
+    pub line: usize,
+    /// 1-based column of `text` within the line, 0 if not known.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// An error about `text`, found at byte `offset` of the string being
+    /// parsed. Use [`Line::locate`] to place it in the input.
+    pub fn new(offset: usize, text: &str, reason: impl Display) -> Self {
+        Self {
+            day: 0,
+            line: 0,
+            column: offset + 1,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// An error about the input as a whole, e.g. something missing.
+    pub fn input(day: usize, reason: impl Display) -> Self {
+        Self {
+            day,
+            line: 0,
+            column: 0,
+            text: String::new(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pos = vec![];
+        if self.day > 0 {
+            pos.push(format!("day {}", self.day));
+        }
+        if self.line > 0 {
+            pos.push(format!("line {}", self.line));
+        }
+        if self.column > 0 {
+            pos.push(format!("column {}", self.column));
+        }
+        if !pos.is_empty() {
+            write!(f, "{}: ", pos.join(", "))?;
+        }
+        write!(f, "{}", self.reason)?;
+        if !self.text.is_empty() {
+            write!(f, ": '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of a puzzle input, used to locate parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: usize,
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// A piece of text not located in an input yet, errors about it can be
+    /// placed later with [`Line::locate`].
+    pub fn detached(text: &'a str) -> Self {
+        Self {
+            day: 0,
+            number: 0,
+            text,
+        }
+    }
+
+    /// Error about `part`, a slice of this line.
+    pub fn error(&self, part: &str, reason: impl Display) -> ParseError {
+        self.locate(part, ParseError::new(0, part, reason))
+    }
+
+    /// Error about something missing at the end of this line.
+    pub fn missing(&self, what: &str) -> ParseError {
+        self.error(&self.text[self.text.len()..], format!("missing {}", what))
+    }
+
+    /// Places an error from parsing `part`, a slice of this line, in the input.
+    pub fn locate(&self, part: &str, mut error: ParseError) -> ParseError {
+        error.day = self.day;
+        error.line = self.number;
+        error.column += self.offset(part);
+        error
+    }
+
+    /// Parses `part`, a slice of this line, with a standard [`FromStr`] impl.
+    pub fn parse<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.parse().map_err(|e| self.error(part, e))
+    }
+
+    /// Extracts the captures of a pattern from this line, see [`scan`](Line::scan_part).
+    pub fn scan<T: FromCaptures>(&self, pattern: &str) -> Result<T, ParseError> {
+        self.scan_part(self.text, pattern)
+    }
+
+    /// Extracts the captures of a pattern from `part`, a slice of this line.
+    ///
+    /// The pattern is literal text with `{}` for the values to capture, which
+    /// can be named, as in `{count}`, to tell them apart in errors. A value
+    /// goes up to the next literal text, or to the end for the last one, and
+    /// is never empty, so that negative numbers work with a `-` after them.
+    /// `{name*sep}` captures a list of values separated by `sep` into a
+    /// `Vec`.
+    ///
+    /// ```
+    /// use adventofcode_2022::parse::Line;
+    ///
+    /// let line = Line::detached("x=-3, y=12 -> a, b");
+    /// let (x, y, names): (i32, usize, Vec<String>) = line.scan("x={x}, y={y} -> {names*, }").unwrap();
+    /// assert_eq!((x, y, names), (-3, 12, vec!["a".to_string(), "b".to_string()]));
+    ///
+    /// let err = line.scan::<(u32, u8, Vec<String>)>("x={x}, y={y} -> {names*, }").unwrap_err();
+    /// assert_eq!(err.to_string(), "column 3: invalid x: invalid digit found in string: '-3'");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is malformed, has two captures in a row or does not
+    /// have as many captures as `T` takes.
+    pub fn scan_part<T: FromCaptures>(
+        &self,
+        part: &'a str,
+        pattern: &str,
+    ) -> Result<T, ParseError> {
+        let tokens = tokenize(pattern);
+        let mut captured = Vec::new();
+        let mut rest = part;
+        for (k, token) in tokens.iter().enumerate() {
+            match *token {
+                Token::Literal(lit) => match rest.strip_prefix(lit) {
+                    Some(r) => rest = r,
+                    None if rest.is_empty() => {
+                        return Err(self.error(rest, format!("missing '{}'", lit)))
+                    }
+                    None => return Err(self.error(rest, format!("expected '{}'", lit))),
+                },
+                Token::Capture(name, sep) => {
+                    let what = if name.is_empty() { "value" } else { name };
+                    if rest.is_empty() {
+                        return Err(self.error(rest, format!("missing {}", what)));
+                    }
+                    // up to the next literal, after at least one char
+                    let first = rest.chars().next().map_or(0, char::len_utf8);
+                    let end = match tokens.get(k + 1) {
+                        Some(Token::Literal(lit)) => match rest[first..].find(lit) {
+                            Some(i) => first + i,
+                            None => {
+                                return Err(
+                                    self.error(rest, format!("missing '{}' after {}", lit, what))
+                                )
+                            }
+                        },
+                        _ => rest.len(),
+                    };
+                    let (text, r) = rest.split_at(end);
+                    rest = r;
+                    match sep {
+                        None => captured.push((name, Captured::One(text))),
+                        Some(sep) => {
+                            let items: Vec<&str> = text.split(sep).collect();
+                            if let Some(item) = items.iter().find(|item| item.is_empty()) {
+                                return Err(self.error(item, format!("missing {}", what)));
+                            }
+                            captured.push((name, Captured::List(items)));
+                        }
+                    }
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(self.error(rest, "unexpected text"));
+        }
+
+        let mut captures = Captures {
+            line: *self,
+            captured: captured.into_iter(),
+        };
+        let res = T::from_captures(&mut captures)?;
+        assert!(
+            captures.captured.next().is_none(),
+            "pattern has too many captures: {}",
+            pattern
+        );
+        Ok(res)
+    }
+
+    // byte offset of a slice of this line
+    fn offset(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let ptr = part.as_ptr() as usize;
+        if ptr >= start && ptr + part.len() <= start + self.text.len() {
+            ptr - start
+        } else {
+            0
+        }
+    }
+}
+
+enum Token<'p> {
+    Literal(&'p str),
+    // name and list separator
+    Capture(&'p str, Option<&'p str>),
+}
+
+fn tokenize(pattern: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            tokens.push(Token::Literal(&rest[..start]));
+        }
+        let len = rest[start..]
+            .find('}')
+            .expect("unclosed capture in pattern");
+        let inner = &rest[start + 1..start + len];
+        let capture = match inner.split_once('*') {
+            Some((name, sep)) if !sep.is_empty() => Token::Capture(name, Some(sep)),
+            Some(_) => panic!("empty list separator in pattern: {}", pattern),
+            None => Token::Capture(inner, None),
+        };
+        if let Some(Token::Capture(..)) = tokens.last() {
+            panic!("two captures in a row in pattern: {}", pattern);
+        }
+        tokens.push(capture);
+        rest = &rest[start + len + 1..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Literal(rest));
+    }
+    tokens
+}
+
+/// The text of a capture of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Captured<'a> {
+    One(&'a str),
+    /// The items of a `{name*sep}` list.
+    List(Vec<&'a str>),
+}
+
+/// The captures of a pattern matched by [`Line::scan`], to be taken in order.
+pub struct Captures<'a> {
+    line: Line<'a>,
+    captured: vec::IntoIter<(&'a str, Captured<'a>)>,
+}
+
+impl Captures<'_> {
+    /// Converts the next capture.
+    pub fn take<T: FromCapture>(&mut self) -> Result<T, ParseError> {
+        let (name, captured) = self.captured.next().expect("pattern has too few captures");
+        T::from_capture(&self.line, name, &captured)
+    }
+}
+
+/// What [`Line::scan`] can extract: a single value, a tuple of them, or a
+/// struct built from them.
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &mut Captures<'_>) -> Result<Self, ParseError>;
+}
+
+impl<T: FromCapture> FromCaptures for T {
+    fn from_captures(captures: &mut Captures<'_>) -> Result<Self, ParseError> {
+        captures.take()
+    }
+}
+
+macro_rules! tuple_captures {
+    ($($t:ident),+) => {
+        impl<$($t: FromCapture),+> FromCaptures for ($($t,)+) {
+            fn from_captures(captures: &mut Captures<'_>) -> Result<Self, ParseError> {
+                Ok(($(captures.take::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+tuple_captures!(A);
+tuple_captures!(A, B);
+tuple_captures!(A, B, C);
+tuple_captures!(A, B, C, D);
+tuple_captures!(A, B, C, D, E);
+tuple_captures!(A, B, C, D, E, F);
+
+/// A value a single capture of a pattern converts to.
+pub trait FromCapture: Sized {
+    /// Converts the text captured as `name` from `line`.
+    fn from_capture(
+        line: &Line<'_>,
+        name: &str,
+        captured: &Captured<'_>,
+    ) -> Result<Self, ParseError>;
+}
+
+macro_rules! from_str_capture {
+    ($($t:ty),*) => {$(
+        impl FromCapture for $t {
+            fn from_capture(line: &Line<'_>, name: &str, captured: &Captured<'_>) -> Result<Self, ParseError> {
+                let Captured::One(text) = captured else {
+                    panic!("list captured into a single value: {}", name);
+                };
+                text.parse().map_err(|e| match name {
+                    "" => line.error(text, e),
+                    _ => line.error(text, format!("invalid {}: {}", name, e)),
+                })
+            }
+        }
+    )*};
+}
+
+from_str_capture!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, char, String);
+
+impl<T: FromCapture> FromCapture for Vec<T> {
+    fn from_capture(
+        line: &Line<'_>,
+        name: &str,
+        captured: &Captured<'_>,
+    ) -> Result<Self, ParseError> {
+        match captured {
+            Captured::One(text) => Ok(vec![T::from_capture(line, name, &Captured::One(text))?]),
+            Captured::List(items) => items
+                .iter()
+                .map(|item| T::from_capture(line, name, &Captured::One(item)))
+                .collect(),
+        }
+    }
+}
+
+/// Splits a puzzle input into numbered lines.
+pub fn lines(day: usize, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let line = lines(7, "a\nfoo 12x bar").nth(1).unwrap();
+        let tok = line.text.split(' ').nth(1).unwrap();
+        let err = line.parse::<usize>(tok).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (7, 2, 5));
+        assert_eq!(err.text, "12x");
+        assert_eq!(
+            err.to_string(),
+            "day 7, line 2, column 5: invalid digit found in string: '12x'"
+        );
+
+        let err = line.locate(&line.text[8..], ParseError::new(2, "r", "unexpected"));
+        assert_eq!(err.column, 11);
+        assert_eq!(line.missing("value").column, 12);
+    }
+
+    #[test]
+    fn scan() {
+        let line = lines(5, "move 3 from 1 to 2").next().unwrap();
+        let (n, src, dst): (usize, usize, usize) = line.scan("move {} from {} to {}").unwrap();
+        assert_eq!((n, src, dst), (3, 1, 2));
+        let n: u8 = line.scan_part(&line.text[5..6], "{}").unwrap();
+        assert_eq!(n, 3);
+
+        // negative numbers, also before a `-`
+        let pair: (i64, i64, i64, i64) = Line::detached("-3--5,7-12").scan("{}-{},{}-{}").unwrap();
+        assert_eq!(pair, (-3, -5, 7, 12));
+
+        // repeated separators
+        let line = Line::detached("tunnels to AA, BB, CC");
+        let (word, valves): (String, Vec<String>) = line.scan("{} to {valves*, }").unwrap();
+        assert_eq!(
+            (word.as_str(), valves.join(" ")),
+            ("tunnels", "AA BB CC".to_string())
+        );
+        let err = line
+            .scan::<Vec<usize>>("tunnels to {valves*, }")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "column 12: invalid valves: invalid digit found in string: 'AA'"
+        );
+        let err = Line::detached("to AA, , CC")
+            .scan::<Vec<String>>("to {valves*, }")
+            .unwrap_err();
+        assert_eq!((err.column, err.reason.as_str()), (8, "missing valves"));
+    }
+
+    #[test]
+    fn scan_errors() {
+        let line = lines(5, "move 3 form 1 to x").next().unwrap();
+        let scan = |pattern| {
+            line.scan::<(usize, usize, usize)>(pattern)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            scan("move {} from {} to {}"),
+            "day 5, line 1, column 6: missing ' from ' after value: '3 form 1 to x'"
+        );
+        assert_eq!(
+            scan("move 3 from {} to {}"),
+            "day 5, line 1, column 1: expected 'move 3 from ': 'move 3 form 1 to x'"
+        );
+        assert_eq!(
+            scan("move {count} form {src} to {dst}"),
+            "day 5, line 1, column 18: invalid dst: invalid digit found in string: 'x'"
+        );
+        assert_eq!(
+            scan("move {} form {} to x{dst} now"),
+            "day 5, line 1, column 19: missing dst"
+        );
+        assert_eq!(
+            scan("move {} form {} to {dst} now"),
+            "day 5, line 1, column 18: missing ' now' after dst: 'x'"
+        );
+        assert_eq!(
+            scan("move {} form {} to x now"),
+            "day 5, line 1, column 13: missing ' to x now' after value: '1 to x'"
+        );
+        assert_eq!(
+            scan("move {} form {} to {src}"),
+            "day 5, line 1, column 18: invalid src: invalid digit found in string: 'x'"
+        );
+        assert_eq!(
+            scan("move {} form {}"),
+            "day 5, line 1, column 13: invalid digit found in string: '1 to x'"
+        );
+        assert_eq!(
+            scan("move {} form {} to"),
+            "day 5, line 1, column 17: unexpected text: ' x'"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "two captures in a row")]
+    fn scan_adjacent_captures() {
+        let _ = Line::detached("12").scan::<(u8, u8)>("{}{}");
+    }
+
+    struct Sensor {
+        at: (i32, i32),
+        range: u32,
+    }
+
+    impl FromCaptures for Sensor {
+        fn from_captures(captures: &mut Captures<'_>) -> Result<Self, ParseError> {
+            Ok(Sensor {
+                at: (captures.take()?, captures.take()?),
+                range: captures.take()?,
+            })
+        }
+    }
+
+    #[test]
+    fn scan_struct() {
+        let sensor: Sensor = Line::detached("at -1,2 range 5")
+            .scan("at {x},{y} range {range}")
+            .unwrap();
+        assert_eq!((sensor.at, sensor.range), ((-1, 2), 5));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseError::input(12, "missing start").to_string(),
+            "day 12: missing start"
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Draws the state of a solution as text, for its debug output.
+//!
+//! A view gives the [`Glyph`] of each of its cells, and a legend of what they
+//! stand for. Glyphs have a [`Tone`] rather than a colour: the [`Theme`] turns
+//! it into an ANSI escape code, unless colours are disabled with `--no-color`
+//! or `$NO_COLOR`, or standard error is not a terminal. Large views can be
+//! cropped to a viewport set with [`init`].
+
+use std::{
+    env,
+    io::{self, IsTerminal},
+    sync::OnceLock,
+};
+
+use crate::{
+    frames::Crop,
+    grid::{Grid, Pos},
+};
+
+/// What a cell is, which decides its colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    Plain,
+    /// Empty space, in the background.
+    Faint,
+    /// Walls and rock.
+    Solid,
+    /// Cells singled out by the solution.
+    Marked,
+    /// Things on the move: sand, pieces, blizzards.
+    Moving,
+    /// Creatures.
+    Alive,
+}
+
+/// How a cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub ch: char,
+    pub tone: Tone,
+}
+
+impl Glyph {
+    pub const fn new(ch: char, tone: Tone) -> Self {
+        Self { ch, tone }
+    }
+}
+
+/// Glyph of a cell when drawn as text.
+pub trait Tile {
+    fn glyph(&self) -> Glyph;
+
+    /// What the glyphs stand for, shown below the grids of tiles.
+    fn legend() -> &'static [(Glyph, &'static str)] {
+        &[]
+    }
+}
+
+/// A grid-like view of a state.
+pub trait Render {
+    /// Width and height of the view, in cells.
+    fn size(&self) -> (usize, usize);
+
+    /// Glyph of the cell at a `(row, column)` position of the view.
+    fn glyph(&self, pos: Pos) -> Glyph;
+
+    /// What the glyphs stand for, shown below the view.
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        &[]
+    }
+
+    /// The view drawn with the options of the program.
+    fn render(&self) -> String {
+        show(self, options())
+    }
+}
+
+impl<T: Tile> Render for Grid<T> {
+    fn size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn glyph(&self, pos: Pos) -> Glyph {
+        self[pos].glyph()
+    }
+
+    fn legend(&self) -> &'static [(Glyph, &'static str)] {
+        T::legend()
+    }
+}
+
+/// ANSI SGR codes of the tones, `Plain` is never coloured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub faint: &'static str,
+    pub solid: &'static str,
+    pub marked: &'static str,
+    pub moving: &'static str,
+    pub alive: &'static str,
+}
+
+impl Theme {
+    pub const DEFAULT: Self = Self {
+        faint: "90",
+        solid: "1",
+        marked: "31",
+        moving: "33",
+        alive: "32",
+    };
+
+    fn code(&self, tone: Tone) -> Option<&'static str> {
+        let code = match tone {
+            Tone::Plain => "",
+            Tone::Faint => self.faint,
+            Tone::Solid => self.solid,
+            Tone::Marked => self.marked,
+            Tone::Moving => self.moving,
+            Tone::Alive => self.alive,
+        };
+        (!code.is_empty()).then_some(code)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub color: bool,
+    pub theme: Theme,
+    /// Part of the views to show, in cells.
+    pub viewport: Option<Crop>,
+}
+
+impl Options {
+    /// Colours only on a terminal, and if `$NO_COLOR` is not set.
+    pub fn from_env() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Self {
+            color: !no_color && io::stderr().is_terminal(),
+            theme: Theme::DEFAULT,
+            viewport: None,
+        }
+    }
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+/// Sets the options of the views for the rest of the program, can only be
+/// done once, before anything is drawn.
+pub fn init(options: Options) -> Result<(), String> {
+    OPTIONS
+        .set(options)
+        .map_err(|_| "rendering was already initialized".to_string())
+}
+
+/// The options set with [`init`], or those of the environment.
+pub fn options() -> &'static Options {
+    OPTIONS.get_or_init(Options::from_env)
+}
+
+/// Draws `view` line by line, then its legend.
+pub fn show<R: Render + ?Sized>(view: &R, options: &Options) -> String {
+    let (width, height) = view.size();
+    let crop = options.viewport.unwrap_or(Crop {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    });
+    let cols = crop.x.min(width)..crop.x.saturating_add(crop.width).min(width);
+    let rows = crop.y.min(height)..crop.y.saturating_add(crop.height).min(height);
+
+    let mut s = String::new();
+    for i in rows.clone() {
+        let line: Vec<Glyph> = cols.clone().map(|j| view.glyph((i, j))).collect();
+        // one escape code for each run of the same tone
+        let mut start = 0;
+        while start < line.len() {
+            let tone = line[start].tone;
+            let end = line[start..]
+                .iter()
+                .position(|g| g.tone != tone)
+                .map_or(line.len(), |k| start + k);
+            let text: String = line[start..end].iter().map(|g| g.ch).collect();
+            push_colored(&mut s, &text, tone, options);
+            start = end;
+        }
+        s.push('\n');
+    }
+
+    if (rows.len(), cols.len()) != (height, width) {
+        s += &format!(
+            "rows {}..{} and columns {}..{} of {}x{}\n",
+            rows.start, rows.end, cols.start, cols.end, width, height
+        );
+    }
+    let legend = view.legend();
+    for (k, &(glyph, meaning)) in legend.iter().enumerate() {
+        if k > 0 {
+            s += "  ";
+        }
+        push_colored(&mut s, &glyph.ch.to_string(), glyph.tone, options);
+        s += &format!(" {}", meaning);
+    }
+    if !legend.is_empty() {
+        s.push('\n');
+    }
+    s
+}
+
+fn push_colored(s: &mut String, text: &str, tone: Tone, options: &Options) {
+    match options.theme.code(tone).filter(|_| options.color) {
+        Some(code) => *s += &format!("\u{1b}[{}m{}\u{1b}[0m", code, text),
+        None => *s += text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    enum Cell {
+        Floor,
+        Wall,
+    }
+
+    const WALL: Glyph = Glyph::new('#', Tone::Solid);
+
+    impl Tile for Cell {
+        fn glyph(&self) -> Glyph {
+            match self {
+                Cell::Floor => Glyph::new('.', Tone::Faint),
+                Cell::Wall => WALL,
+            }
+        }
+    }
+
+    struct Room(Grid<Cell>);
+
+    impl Render for Room {
+        fn size(&self) -> (usize, usize) {
+            self.0.size()
+        }
+
+        fn glyph(&self, pos: Pos) -> Glyph {
+            self.0.glyph(pos)
+        }
+
+        fn legend(&self) -> &'static [(Glyph, &'static str)] {
+            &[(WALL, "wall")]
+        }
+    }
+
+    fn room() -> Room {
+        Room(Grid::from_fn(4, 3, |(i, j)| {
+            if i == 0 || j == 0 {
+                Cell::Wall
+            } else {
+                Cell::Floor
+            }
+        }))
+    }
+
+    const PLAIN: Options = Options {
+        color: false,
+        theme: Theme::DEFAULT,
+        viewport: None,
+    };
+
+    #[test]
+    fn plain() {
+        assert_eq!(show(&room(), &PLAIN), "####\n#...\n#...\n# wall\n");
+        // no legend
+        assert_eq!(show(&room().0, &PLAIN), "####\n#...\n#...\n");
+    }
+
+    #[test]
+    fn colors() {
+        let options = Options {
+            color: true,
+            ..PLAIN
+        };
+        assert_eq!(
+            show(&room().0, &options),
+            "\u{1b}[1m####\u{1b}[0m\n\u{1b}[1m#\u{1b}[0m\u{1b}[90m...\u{1b}[0m\n\u{1b}[1m#\u{1b}[0m\u{1b}[90m...\u{1b}[0m\n"
+        );
+        // a tone without a code
+        let theme = Theme {
+            faint: "",
+            ..Theme::DEFAULT
+        };
+        let options = Options {
+            color: true,
+            theme,
+            ..PLAIN
+        };
+        assert_eq!(
+            show(&room().0, &options),
+            "\u{1b}[1m####\u{1b}[0m\n\u{1b}[1m#\u{1b}[0m...\n\u{1b}[1m#\u{1b}[0m...\n"
+        );
+    }
+
+    #[test]
+    fn viewport() {
+        let crop = |x, y, width, height| Options {
+            viewport: Some(Crop {
+                x,
+                y,
+                width,
+                height,
+            }),
+            ..PLAIN
+        };
+        assert_eq!(
+            show(&room().0, &crop(1, 1, 2, 5)),
+            "..\n..\nrows 1..3 and columns 1..3 of 4x3\n"
+        );
+        // the whole view
+        assert_eq!(show(&room().0, &crop(0, 0, 10, 10)), "####\n#...\n#...\n");
+        // outside of the view
+        assert_eq!(
+            show(&room().0, &crop(5, 0, 1, 1)),
+            "\nrows 0..1 and columns 4..4 of 4x3\n"
+        );
+        // past the end of the numbers
+        assert_eq!(
+            show(&room().0, &crop(3, 2, usize::MAX, usize::MAX)),
+            ".\nrows 2..3 and columns 3..4 of 4x3\n"
+        );
+    }
+}
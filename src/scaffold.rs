@@ -0,0 +1,230 @@
+//! Skeletons for the solutions of new days.
+//!
+//! A new day gets a `src/dayN.rs` module from `templates/day.rs`, registered
+//! in the runner and the input generators, and an empty `dayN.txt` in the
+//! input directory to paste the input into (or to [fetch](crate::client) it).
+//! A day past the last one raises [`DAYS`](crate::DAYS).
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The module of a new day.
+pub fn module(day: usize) -> String {
+    TEMPLATE.replace("{{day}}", &day.to_string())
+}
+
+/// Declares the module of a day in `lib.rs` and adds it to the solvers,
+/// unless it is there already, and raises the number of days up to it.
+pub fn register(lib: &str, day: usize) -> Result<String, ScaffoldError> {
+    let decl = format!("pub mod day{};", day);
+    let lib = if lib.lines().any(|line| line == decl) {
+        lib.to_string()
+    } else {
+        let last = lib
+            .lines()
+            .rfind(|line| line.starts_with("pub mod day"))
+            .ok_or(ScaffoldError::Register("lib.rs", "day modules"))?;
+        insert_after(lib, last, &decl)
+    };
+    let lib = add_arm(
+        &lib,
+        "lib.rs",
+        &format!("{} => day{}::Day{}::run,", day, day, day),
+    )?;
+    raise_days(&lib, day)
+}
+
+/// Adds a day to the input generators in `gen.rs`, unless it is there
+/// already.
+pub fn register_generator(gen: &str, day: usize) -> Result<String, ScaffoldError> {
+    add_arm(
+        gen,
+        "gen.rs",
+        &format!("{} => run::<day{}::Day{}>(seed, size),", day, day, day),
+    )
+}
+
+fn raise_days(lib: &str, day: usize) -> Result<String, ScaffoldError> {
+    const DECL: &str = "pub const DAYS: usize = ";
+    let (line, days) = lib
+        .lines()
+        .find_map(|line| {
+            let days = line.strip_prefix(DECL)?.strip_suffix(';')?;
+            Some((line, days.parse::<usize>().ok()?))
+        })
+        .ok_or(ScaffoldError::Register("lib.rs", "number of days"))?;
+    if day <= days {
+        return Ok(lib.to_string());
+    }
+    Ok(lib.replacen(line, &format!("{}{};", DECL, day), 1))
+}
+
+fn insert_after(text: &str, line: &str, new: &str) -> String {
+    let mut res = String::new();
+    for l in text.split_inclusive('\n') {
+        res.push_str(l);
+        if l.trim_end_matches('\n') == line {
+            res.push_str(new);
+            res.push('\n');
+        }
+    }
+    res
+}
+
+// adds a match arm before the fallback one of the day dispatch
+fn add_arm(text: &str, file: &'static str, arm: &str) -> Result<String, ScaffoldError> {
+    let (_, path) = arm.split_once(" => ").expect("not an arm");
+    if text.contains(path) {
+        return Ok(text.to_string());
+    }
+    let fallback = text
+        .lines()
+        .find(|line| line.trim() == "_ => return None,")
+        .ok_or(ScaffoldError::Register(file, "days"))?;
+    let indent = &fallback[..fallback.len() - fallback.trim_start().len()];
+    Ok(text.replacen(fallback, &format!("{}{}\n{}", indent, arm, fallback), 1))
+}
+
+/// Creates the files of a new day in the crate at `root`, returns them.
+pub fn create(root: &Path, input_dir: &Path, day: usize) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let module_path = src.join(format!("day{}.rs", day));
+    if module_path.exists() {
+        return Err(ScaffoldError::Exists(module_path));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+    };
+    let write = |path: &Path, text: &str| {
+        fs::write(path, text).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+    };
+    let (lib_path, gen_path) = (src.join("lib.rs"), src.join("gen.rs"));
+    let lib = register(&read(&lib_path)?, day)?;
+    let gen = register_generator(&read(&gen_path)?, day)?;
+
+    write(&module_path, &module(day))?;
+    write(&lib_path, &lib)?;
+    write(&gen_path, &gen)?;
+    let mut created = vec![module_path];
+    let input_path = input_dir.join(format!("day{}.txt", day));
+    if !input_path.exists() {
+        write(&input_path, "")?;
+        created.push(input_path);
+    }
+    Ok(created)
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The module of the day is there already.
+    Exists(PathBuf),
+    /// Where to register the day could not be found in a file.
+    Register(&'static str, &'static str),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "{} exists already", path.display()),
+            Self::Register(file, what) => write!(f, "cannot find the {} in {}", what, file),
+            Self::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const LIB: &str = "\
+pub mod day1;
+pub mod day2;
+
+pub const DAYS: usize = 2;
+
+pub fn solver(day: usize) -> Option<Solver> {
+    let solve: Solver = match day {
+        1 => day1::Day1::run,
+        2 => day2::Day2::run,
+        _ => return None,
+    };
+    Some(solve)
+}
+";
+
+    #[test]
+    fn template() {
+        let module = module(7);
+        assert!(module.contains("pub struct Day7;"));
+        assert!(module.contains("const DAY: usize = 7;"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn registration() {
+        let lib = register(LIB, 3).unwrap();
+        assert!(lib.starts_with("pub mod day1;\npub mod day2;\npub mod day3;\n\n"));
+        assert!(lib.contains("pub const DAYS: usize = 3;\n"));
+        assert!(lib.contains("        2 => day2::Day2::run,\n        3 => day3::Day3::run,\n        _ => return None,\n"));
+        // only once
+        assert_eq!(register(&lib, 3).unwrap(), lib);
+        assert!(matches!(
+            register("", 3),
+            Err(ScaffoldError::Register("lib.rs", _))
+        ));
+
+        let gen =
+            register_generator("    match day {\n        _ => return None,\n    }", 3).unwrap();
+        assert_eq!(gen, "    match day {\n        3 => run::<day3::Day3>(seed, size),\n        _ => return None,\n    }");
+    }
+
+    #[test]
+    fn create_files() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(
+            root.join("src/gen.rs"),
+            "match day {\n    _ => return None,\n}\n",
+        )
+        .unwrap();
+
+        let created = create(&root, &root, 3).unwrap();
+        assert_eq!(
+            created,
+            vec![root.join("src/day3.rs"), root.join("day3.txt")]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/day3.rs")).unwrap(),
+            module(3)
+        );
+        assert_eq!(fs::read_to_string(root.join("day3.txt")).unwrap(), "");
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod day3;"));
+        assert!(matches!(
+            create(&root, &root, 3),
+            Err(ScaffoldError::Exists(_))
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,633 @@
+//! Searches through the states of a puzzle.
+//!
+//! A puzzle implements [`SearchProblem`], describing where the search starts
+//! and how to move from a node to the next ones, and is searched with one of
+//! the [`Strategy`] kinds:
+//!
+//! ```
+//! use adventofcode_2022::search::{Search, SearchProblem, Strategy};
+//!
+//! // from 1 to a number, doubling or adding one
+//! struct Reach(u32);
+//!
+//! impl SearchProblem for Reach {
+//!     type Node = u32;
+//!     type Key = u32;
+//!
+//!     fn start(&self) -> u32 { 1 }
+//!     fn key(&self, &n: &u32) -> u32 { n }
+//!     fn expand(&self, &n: &u32) -> Vec<(u32, usize)> {
+//!         [n + 1, 2 * n].into_iter().filter(|&m| m <= self.0).map(|m| (m, 1)).collect()
+//!     }
+//!     fn is_goal(&self, &n: &u32) -> bool { n == self.0 }
+//! }
+//!
+//! let outcome = Search::new(&Reach(10)).paths(true).run(Strategy::Bfs);
+//! let found = outcome.found.unwrap();
+//! assert_eq!((found.score, found.path), (4, vec![1, 2, 4, 5, 10]));
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map, BinaryHeap, HashMap, VecDeque},
+    fmt::Display,
+    hash::{BuildHasherDefault, Hash, Hasher},
+};
+
+/// A graph to search, given by its nodes and the moves between them.
+pub trait SearchProblem {
+    type Node: Clone;
+    /// What tells apart nodes that are the same state, to visit them once.
+    ///
+    /// For branch and bound, nodes with the same key may only differ by
+    /// their value, which must add the same to all of the nodes they lead
+    /// to: a node is expanded again if it has a higher value than those
+    /// with its key before.
+    type Key: Hash + Eq;
+
+    fn start(&self) -> Self::Node;
+
+    fn key(&self, node: &Self::Node) -> Self::Key;
+
+    /// The nodes reachable from a node, with the cost of getting there.
+    fn expand(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+
+    /// Whether the search can stop at a node, unused by branch and bound.
+    fn is_goal(&self, _node: &Self::Node) -> bool {
+        false
+    }
+
+    /// For A*: an estimate of the cost still needed to reach a goal. It must
+    /// not be more than that cost, nor decrease more than the cost of a move.
+    fn heuristic(&self, _node: &Self::Node) -> usize {
+        0
+    }
+
+    /// For branch and bound: the value of a node as a solution.
+    fn value(&self, _node: &Self::Node) -> usize {
+        0
+    }
+
+    /// For branch and bound: at least the value of any node reachable from
+    /// this one, itself included.
+    fn bound(&self, _node: &Self::Node) -> usize {
+        usize::MAX
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Breadth first: the goal with the fewest moves.
+    Bfs,
+    /// Depth first: any goal.
+    Dfs,
+    /// Cheapest first: the goal with the lowest cost.
+    Dijkstra,
+    /// Cheapest first with the estimate of the heuristic added: the goal
+    /// with the lowest cost, looking at fewer nodes.
+    AStar,
+    /// Highest value first through all the nodes: the one with the highest
+    /// value, skipping the nodes that cannot beat it according to their
+    /// bound.
+    BranchAndBound,
+}
+
+/// Counts of what happened to the nodes during a search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes whose moves were generated.
+    pub expanded: usize,
+    /// Nodes reached by moves.
+    pub generated: usize,
+    /// Nodes skipped for their bound.
+    pub pruned: usize,
+    /// Nodes skipped because their state was expanded already, with at least
+    /// their value for branch and bound.
+    pub deduped: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} expanded, {} generated, {} pruned, {} deduped",
+            self.expanded, self.generated, self.pruned, self.deduped
+        )
+    }
+}
+
+/// The node a search ended on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<N> {
+    pub node: N,
+    /// The cost of the path to the node, or its value for branch and bound.
+    pub score: usize,
+    /// The nodes from the start to this one, if the paths were kept.
+    pub path: Vec<N>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome<N> {
+    /// The goal or the best node, `None` if no goal could be reached.
+    pub found: Option<Found<N>>,
+    pub stats: Stats,
+}
+
+/// A search of a problem, with its options.
+pub struct Search<'p, P> {
+    problem: &'p P,
+    dedupe: bool,
+    paths: bool,
+}
+
+impl<'p, P: SearchProblem> Search<'p, P> {
+    pub fn new(problem: &'p P) -> Self {
+        Self {
+            problem,
+            dedupe: true,
+            paths: false,
+        }
+    }
+
+    /// Whether to visit each state once, on by default. Off, keys are not
+    /// computed, for problems where states rarely repeat.
+    pub fn dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Whether to keep the path to the found node, off by default as it
+    /// keeps all the expanded nodes.
+    pub fn paths(mut self, paths: bool) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    pub fn run(&self, strategy: Strategy) -> Outcome<P::Node> {
+        let problem = self.problem;
+        let bnb = strategy == Strategy::BranchAndBound;
+        // without weights a node is never reached cheaper later: it is closed
+        // as soon as it is pushed, and pushed only once
+        let unweighted = matches!(strategy, Strategy::Bfs | Strategy::Dfs);
+        let mut stats = Stats::default();
+        // the keys of the closed nodes, with the best value for branch and bound
+        let mut closed: HashMap<P::Key, usize, BuildHasherDefault<KeyHasher>> = HashMap::default();
+        let value = |node: &P::Node| if bnb { problem.value(node) } else { 0 };
+        // the expanded nodes and their parents, to follow the paths back
+        let mut tree: Vec<(P::Node, Option<usize>)> = Vec::new();
+        // for branch and bound: the best node so far, its value and parent
+        let mut best: Option<(P::Node, usize, Option<usize>)> = None;
+        let beaten = |best: &Option<(P::Node, usize, Option<usize>)>, bound: usize| {
+            best.as_ref().is_some_and(|&(_, value, _)| bound <= value)
+        };
+
+        let priority = |cost: usize, node: &P::Node| match strategy {
+            Strategy::Dijkstra => cost,
+            Strategy::AStar => cost + problem.heuristic(node),
+            // highest value first
+            Strategy::BranchAndBound => usize::MAX - problem.value(node),
+            _ => 0,
+        };
+
+        let mut frontier = Frontier::new(strategy);
+        let start = problem.start();
+        if self.dedupe && unweighted {
+            closed.insert(problem.key(&start), 0);
+        }
+        frontier.push(Entry {
+            priority: priority(0, &start),
+            bound: if bnb { problem.bound(&start) } else { 0 },
+            seq: 0,
+            node: start,
+            cost: 0,
+            parent: None,
+        });
+        let mut seq = 1;
+
+        while let Some(entry) = frontier.pop() {
+            // the best may have improved since it was pushed
+            if bnb && beaten(&best, entry.bound) {
+                stats.pruned += 1;
+                continue;
+            }
+            if self.dedupe && !unweighted {
+                let value = value(&entry.node);
+                match closed.entry(problem.key(&entry.node)) {
+                    hash_map::Entry::Vacant(closing) => {
+                        closing.insert(value);
+                    }
+                    hash_map::Entry::Occupied(mut closing) if value > *closing.get() => {
+                        closing.insert(value);
+                    }
+                    hash_map::Entry::Occupied(_) => {
+                        stats.deduped += 1;
+                        continue;
+                    }
+                }
+            }
+            if !bnb && problem.is_goal(&entry.node) {
+                let path = self.path(&tree, entry.parent, &entry.node);
+                let found = Found {
+                    node: entry.node,
+                    score: entry.cost,
+                    path,
+                };
+                return Outcome {
+                    found: Some(found),
+                    stats,
+                };
+            }
+
+            stats.expanded += 1;
+            let id = self.paths.then(|| {
+                tree.push((entry.node.clone(), entry.parent));
+                tree.len() - 1
+            });
+            if bnb {
+                let value = problem.value(&entry.node);
+                if best.as_ref().is_none_or(|&(_, best, _)| value > best) {
+                    best = Some((entry.node.clone(), value, entry.parent));
+                }
+            }
+
+            for (node, step) in problem.expand(&entry.node) {
+                stats.generated += 1;
+                // bounds are usually cheaper than keys
+                let bound = if bnb { problem.bound(&node) } else { 0 };
+                if bnb && beaten(&best, bound) {
+                    stats.pruned += 1;
+                    continue;
+                }
+                if self.dedupe {
+                    let key = problem.key(&node);
+                    let seen = if unweighted {
+                        closed.insert(key, 0).is_some()
+                    } else {
+                        closed.get(&key).is_some_and(|&best| best >= value(&node))
+                    };
+                    if seen {
+                        stats.deduped += 1;
+                        continue;
+                    }
+                }
+                let cost = entry.cost + step;
+                frontier.push(Entry {
+                    priority: priority(cost, &node),
+                    bound,
+                    seq,
+                    node,
+                    cost,
+                    parent: id,
+                });
+                seq += 1;
+            }
+        }
+
+        let found = best.map(|(node, score, parent)| Found {
+            path: self.path(&tree, parent, &node),
+            node,
+            score,
+        });
+        Outcome { found, stats }
+    }
+
+    // the path to a node with the given parent, if the paths are kept
+    fn path(
+        &self,
+        tree: &[(P::Node, Option<usize>)],
+        mut parent: Option<usize>,
+        node: &P::Node,
+    ) -> Vec<P::Node> {
+        if !self.paths {
+            return Vec::new();
+        }
+        let mut path = vec![node.clone()];
+        while let Some(id) = parent {
+            path.push(tree[id].0.clone());
+            parent = tree[id].1;
+        }
+        path.reverse();
+        path
+    }
+}
+
+struct Entry<N> {
+    // lowest first, then oldest first
+    priority: usize,
+    seq: usize,
+    // for branch and bound
+    bound: usize,
+    node: N,
+    cost: usize,
+    // the parent in the tree of expanded nodes
+    parent: Option<usize>,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> Ord for Entry<N> {
+    // reversed for the max-heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.priority, other.seq).cmp(&(self.priority, self.seq))
+    }
+}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// multiplies and rotates each word in, much cheaper than the default hasher
+// for the small keys of states, which need no protection from collisions
+#[derive(Default)]
+struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_u8(&mut self, n: u8) {
+        self.write_u64(n.into());
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write_u64(n.into());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write_u64(n.into());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
+
+enum Frontier<N> {
+    Queue(VecDeque<Entry<N>>),
+    Stack(Vec<Entry<N>>),
+    Heap(BinaryHeap<Entry<N>>),
+}
+
+impl<N> Frontier<N> {
+    fn new(strategy: Strategy) -> Self {
+        match strategy {
+            Strategy::Bfs => Self::Queue(VecDeque::new()),
+            Strategy::Dfs => Self::Stack(Vec::new()),
+            Strategy::Dijkstra | Strategy::AStar | Strategy::BranchAndBound => {
+                Self::Heap(BinaryHeap::new())
+            }
+        }
+    }
+
+    fn push(&mut self, entry: Entry<N>) {
+        match self {
+            Self::Queue(queue) => queue.push_back(entry),
+            Self::Stack(stack) => stack.push(entry),
+            Self::Heap(heap) => heap.push(entry),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Entry<N>> {
+        match self {
+            Self::Queue(queue) => queue.pop_front(),
+            Self::Stack(stack) => stack.pop(),
+            Self::Heap(heap) => heap.pop(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a grid of weights, moving right or down from the top left corner
+    struct Weights(Vec<Vec<usize>>);
+
+    impl SearchProblem for Weights {
+        type Node = (usize, usize);
+        type Key = (usize, usize);
+
+        fn start(&self) -> Self::Node {
+            (0, 0)
+        }
+
+        fn key(&self, &node: &Self::Node) -> Self::Key {
+            node
+        }
+
+        fn expand(&self, &(i, j): &Self::Node) -> Vec<(Self::Node, usize)> {
+            [(i + 1, j), (i, j + 1)]
+                .into_iter()
+                .filter(|&(i, j)| i < self.0.len() && j < self.0[0].len())
+                .map(|(i, j)| ((i, j), self.0[i][j]))
+                .collect()
+        }
+
+        fn is_goal(&self, &(i, j): &Self::Node) -> bool {
+            (i, j) == (self.0.len() - 1, self.0[0].len() - 1)
+        }
+
+        fn heuristic(&self, &(i, j): &Self::Node) -> usize {
+            (self.0.len() - 1 - i) + (self.0[0].len() - 1 - j)
+        }
+
+        // the cheapest path that collects the most weight, if it is the goal
+        fn value(&self, node: &Self::Node) -> usize {
+            if self.is_goal(node) {
+                100
+            } else {
+                0
+            }
+        }
+
+        fn bound(&self, _node: &Self::Node) -> usize {
+            100
+        }
+    }
+
+    const WEIGHTS: [[usize; 4]; 3] = [[1, 9, 1, 1], [1, 9, 1, 9], [1, 1, 1, 1]];
+
+    fn weights() -> Weights {
+        Weights(WEIGHTS.iter().map(|row| row.to_vec()).collect())
+    }
+
+    #[test]
+    fn strategies() {
+        let problem = weights();
+        let run = |strategy| Search::new(&problem).paths(true).run(strategy);
+
+        for strategy in [Strategy::Dijkstra, Strategy::AStar] {
+            let found = run(strategy).found.unwrap();
+            assert_eq!(found.score, 5, "{:?}", strategy);
+            assert_eq!(found.path, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]);
+        }
+        // the fewest moves, then the first found
+        let found = run(Strategy::Bfs).found.unwrap();
+        assert_eq!((found.path.len(), found.node), (6, (2, 3)));
+        let found = run(Strategy::Dfs).found.unwrap();
+        assert_eq!((found.path.len(), found.node), (6, (2, 3)));
+        assert!(found
+            .path
+            .windows(2)
+            .all(|w| problem.expand(&w[0]).iter().any(|(n, _)| *n == w[1])));
+
+        // the heuristic spares some nodes
+        let dijkstra = run(Strategy::Dijkstra).stats;
+        let astar = run(Strategy::AStar).stats;
+        assert!(
+            astar.expanded <= dijkstra.expanded,
+            "{} / {}",
+            astar,
+            dijkstra
+        );
+
+        // without paths
+        let found = Search::new(&problem).run(Strategy::AStar).found.unwrap();
+        assert_eq!((found.score, found.path), (5, vec![]));
+    }
+
+    #[test]
+    fn branch_and_bound() {
+        let problem = weights();
+        let outcome = Search::new(&problem)
+            .paths(true)
+            .run(Strategy::BranchAndBound);
+        let found = outcome.found.unwrap();
+        assert_eq!(
+            (found.node, found.score, found.path.len()),
+            ((2, 3), 100, 6)
+        );
+        // nothing beats the goal once found
+        assert!(outcome.stats.pruned > 0, "{}", outcome.stats);
+        assert_eq!(
+            outcome.stats.expanded + outcome.stats.pruned + outcome.stats.deduped,
+            outcome.stats.generated + 1
+        );
+    }
+
+    #[test]
+    fn dedupe() {
+        let problem = weights();
+        let with = Search::new(&problem).run(Strategy::Bfs).stats;
+        let without = Search::new(&problem).dedupe(false).run(Strategy::Bfs).stats;
+        assert!(with.deduped > 0);
+        assert_eq!(without.deduped, 0);
+        assert!(with.expanded < without.expanded, "{} / {}", with, without);
+    }
+
+    #[test]
+    fn dedupe_by_value() {
+        // the most weight collected on the way to the bottom right corner
+        struct Collect(Weights);
+
+        impl SearchProblem for Collect {
+            type Node = ((usize, usize), usize);
+            type Key = (usize, usize);
+
+            fn start(&self) -> Self::Node {
+                ((0, 0), self.0 .0[0][0])
+            }
+
+            fn key(&self, &(pos, _): &Self::Node) -> Self::Key {
+                pos
+            }
+
+            fn expand(&self, &(pos, weight): &Self::Node) -> Vec<(Self::Node, usize)> {
+                self.0
+                    .expand(&pos)
+                    .into_iter()
+                    .map(|(next, step)| ((next, weight + step), 1))
+                    .collect()
+            }
+
+            fn value(&self, &(_, weight): &Self::Node) -> usize {
+                weight
+            }
+        }
+
+        let problem = Collect(weights());
+        let with = Search::new(&problem).run(Strategy::BranchAndBound);
+        let without = Search::new(&problem)
+            .dedupe(false)
+            .run(Strategy::BranchAndBound);
+        assert_eq!(with.found.unwrap().node, ((2, 3), 30));
+        assert_eq!(without.found.unwrap().node, ((2, 3), 30));
+        assert!(with.stats.deduped > 0);
+        assert!(
+            with.stats.expanded < without.stats.expanded,
+            "{} / {}",
+            with.stats,
+            without.stats
+        );
+    }
+
+    #[test]
+    fn unreachable() {
+        // the goal is behind the start
+        struct Backwards(Weights);
+
+        impl SearchProblem for Backwards {
+            type Node = (usize, usize);
+            type Key = (usize, usize);
+
+            fn start(&self) -> Self::Node {
+                (1, 1)
+            }
+
+            fn key(&self, &node: &Self::Node) -> Self::Key {
+                node
+            }
+
+            fn expand(&self, node: &Self::Node) -> Vec<(Self::Node, usize)> {
+                self.0.expand(node)
+            }
+
+            fn is_goal(&self, &node: &Self::Node) -> bool {
+                node == (0, 0)
+            }
+        }
+
+        let problem = Backwards(weights());
+        for strategy in [
+            Strategy::Bfs,
+            Strategy::Dfs,
+            Strategy::Dijkstra,
+            Strategy::AStar,
+        ] {
+            let outcome = Search::new(&problem).run(strategy);
+            assert_eq!(outcome.found, None);
+            let stats = outcome.stats;
+            assert_eq!(stats.expanded, 6, "{:?}", strategy);
+            if matches!(strategy, Strategy::Bfs | Strategy::Dfs) {
+                // every node but the start pushed once
+                assert_eq!(
+                    stats.generated - stats.deduped,
+                    stats.expanded - 1,
+                    "{:?}",
+                    strategy
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{frames, ParseError};
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has no puzzle (the second half of day 25).
+    None,
+    /// The part could not be solved for this input, and why.
+    Unsolved(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::None => write!(f, "-"),
+            Self::Unsolved(reason) => write!(f, "unsolved: {}", reason),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Number(value as i64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Self::Number(value as i64)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl<T: Into<Answer>> From<Result<T, String>> for Answer {
+    fn from(value: Result<T, String>) -> Self {
+        value.map_or_else(Self::Unsolved, Into::into)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&(*self as usize), f)
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("expected part 1 or 2, got '{}'", s)),
+        }
+    }
+}
+
+/// Answers computed by [`Solution::run`], with the time spent in each phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse_time: Duration,
+    /// Answer and solving time of each requested part, in order.
+    pub parts: Vec<(Part, Answer, Duration)>,
+}
+
+impl Run {
+    pub fn answers(&self) -> impl Iterator<Item = &Answer> {
+        self.parts.iter().map(|(_, answer, _)| answer)
+    }
+
+    /// Time spent solving `part`, if it was requested.
+    pub fn part_time(&self, part: Part) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|(p, _, _)| *p == part)
+            .map(|&(_, _, time)| time)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time
+            + self
+                .parts
+                .iter()
+                .map(|&(_, _, time)| time)
+                .sum::<Duration>()
+    }
+}
+
+/// A puzzle solver, split into parsing the input and computing each part.
+pub trait Solution {
+    /// Number of the day, used to locate parse errors.
+    const DAY: usize;
+
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    fn solve_part(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Parses `input` and computes the answers to both parts.
+    fn solve(input: &str) -> Result<(Answer, Answer), ParseError> {
+        let input = Self::parse(input)?;
+        Ok((Self::part1(&input), Self::part2(&input)))
+    }
+
+    /// Parses `input` and computes the answers to the given parts only,
+    /// timing each phase.
+    fn run(input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let input = Self::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                frames::begin(Self::DAY, part);
+                let start = Instant::now();
+                let answer = Self::solve_part(&input, part);
+                (part, answer, start.elapsed())
+            })
+            .collect();
+        Ok(Run { parse_time, parts })
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    gen::{Generator, Rng},
+    parse, Answer, ParseError, Solution,
+};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: usize = {{day}};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, input)
+            .map(|line| Ok(line.text.to_string()))
+            .collect()
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::None
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+// `size` random lines
+impl Generator for Day{{day}} {
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| rng.word(8) + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    const SAMPLE: &str = "\
+";
+
+    #[test]
+    fn sample() {
+        assert_eq!(Day{{day}}::solve(SAMPLE), Ok((Answer::None, Answer::None)));
+    }
+
+    #[test]
+    fn real_input() {
+        let input = Source::Day({{day}}).read().expect("cannot read input");
+        assert_eq!(Day{{day}}::solve(&input), Ok((Answer::None, Answer::None)));
+    }
+}